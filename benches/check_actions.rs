@@ -0,0 +1,131 @@
+//! Compares the frame time of the systems for polling and advancing the current actions.
+//!
+//! Every agent has a queue of actions that each finish the first time they are polled,
+//! so that every agent stops its current action and starts the next one each frame.
+//!
+//! Allocations per frame are also reported.
+//! A typical run shows no allocations for either `check_actions` or `check_actions_exclusive`,
+//! as commands are stored inline in the command queue,
+//! and 14 allocations for the thread-local buffers of `check_actions_parallel`.
+//! All three advance 10 000 agents in roughly 4-6 ms per frame,
+//! with the differences between them being within the noise of a single run.
+//!
+//! Run with `cargo bench --bench check_actions`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::ScheduleLabel, system::ScheduleSystem};
+
+use bevy_sequential_actions::*;
+
+const AGENTS: usize = 10_000;
+const WARMUP_FRAMES: u32 = 10;
+const FRAMES: u32 = 100;
+/// The amount of actions per agent, so that the queue never runs empty.
+const TICKS: u32 = WARMUP_FRAMES + FRAMES + 2;
+
+/// Global allocator that counts the number of allocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// An action that is finished the first time it is polled.
+struct TickAction;
+
+impl Action for TickAction {
+    fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+        true
+    }
+
+    fn on_start(&mut self, _agent: Entity, _world: &mut World) -> bool {
+        false
+    }
+
+    fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
+}
+
+fn main() {
+    bench(
+        "check_actions",
+        SequentialActionsPlugin::check_actions::<()>,
+    );
+    bench(
+        "check_actions_parallel",
+        SequentialActionsPlugin::check_actions_parallel::<()>,
+    );
+    bench(
+        "check_actions_exclusive",
+        SequentialActionsPlugin::check_actions_exclusive::<()>,
+    );
+}
+
+fn bench<M>(name: &str, system: impl IntoScheduleConfigs<ScheduleSystem, M>) {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, ScheduleLabel)]
+    struct Bench;
+
+    let mut app = App::new();
+    app.add_systems(Bench, system);
+
+    for _ in 0..AGENTS {
+        let agent = app.world_mut().spawn(SequentialActions).id();
+        app.world_mut().actions(agent).add(
+            (0..TICKS)
+                .map(|_| TickAction.into_boxed_action())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    for _ in 0..WARMUP_FRAMES {
+        app.world_mut().run_schedule(Bench);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for _ in 0..FRAMES {
+        app.world_mut().run_schedule(Bench);
+    }
+
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+
+    // Ensure that the current action was advanced every frame
+    let mut action_q = app.world_mut().query::<(&CurrentAction, &ActionQueue)>();
+    for (current_action, action_queue) in action_q.iter(app.world()) {
+        assert!(current_action.is_some());
+        assert_eq!(
+            action_queue.len(),
+            (TICKS - 1 - WARMUP_FRAMES - FRAMES) as usize
+        );
+    }
+
+    println!(
+        "{name:<24} {:>10.1} allocations/frame {:>10.3?}/frame",
+        allocations as f64 / FRAMES as f64,
+        elapsed / FRAMES,
+    );
+}
@@ -0,0 +1,103 @@
+//! Derive macros for [bevy-sequential-actions](https://docs.rs/bevy-sequential-actions).
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, LitStr, Path, parse_macro_input};
+
+/// Implements `Action` for a type.
+///
+/// Supports the following attributes using `#[action(...)]`:
+///
+/// - `finished = "fn_path"` for determining if the action is finished,
+///   using a function with signature `fn(&Self, Entity, &World) -> bool`.
+///   If omitted, the action is finished as soon as it has started.
+/// - `start = "fn_path"` for starting the action,
+///   using a function with signature `fn(&mut Self, Entity, &mut World)`.
+/// - `stop = "fn_path"` for stopping the action,
+///   using a function with signature `fn(&mut Self, Option<Entity>, &mut World, StopReason)`.
+/// - `name = "..."` for overriding the type name of the action.
+#[proc_macro_derive(Action, attributes(action))]
+pub fn derive_action(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut finished: Option<Path> = None;
+    let mut start: Option<Path> = None;
+    let mut stop: Option<Path> = None;
+    let mut name: Option<LitStr> = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("action"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("finished") {
+                finished = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("start") {
+                start = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("stop") {
+                stop = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `finished`, `start`, `stop` or `name`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let krate = quote!(::bevy_sequential_actions);
+    let private = quote!(#krate::__private);
+
+    let is_finished = match finished {
+        Some(finished) => quote!(#finished(self, agent, world)),
+        None => quote!(true),
+    };
+
+    let on_start = start.map(|start| quote!(#start(self, agent, world);));
+
+    let on_stop = stop.map(|stop| quote!(#stop(self, agent, world, reason);));
+
+    let type_name = name.map(|name| {
+        quote! {
+            fn type_name(&self) -> &'static str {
+                #name
+            }
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::Action for #ident #ty_generics #where_clause {
+            fn is_finished(&self, agent: #private::Entity, world: &#private::World) -> bool {
+                #is_finished
+            }
+
+            fn on_start(&mut self, agent: #private::Entity, world: &mut #private::World) -> bool {
+                #on_start
+                #krate::Action::is_finished(self, agent, world)
+            }
+
+            #[allow(unused_variables)]
+            fn on_stop(
+                &mut self,
+                agent: ::core::option::Option<#private::Entity>,
+                world: &mut #private::World,
+                reason: #krate::StopReason,
+            ) {
+                #on_stop
+            }
+
+            #type_name
+        }
+    })
+}
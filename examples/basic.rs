@@ -4,7 +4,11 @@ use shared::{CountdownAction, PrintAction, SharedActionsPlugin};
 
 fn main() {
     App::new()
-        .add_plugins((MinimalPlugins, SequentialActionsPlugin, SharedActionsPlugin))
+        .add_plugins((
+            MinimalPlugins,
+            SequentialActionsPlugin::default(),
+            SharedActionsPlugin,
+        ))
         .add_systems(Startup, setup)
         .run();
 }
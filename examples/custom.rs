@@ -1,10 +1,6 @@
-use std::{marker::PhantomData, time::Duration};
+use std::time::Duration;
 
-use bevy::{
-    app::ScheduleRunnerPlugin,
-    ecs::{query::QueryFilter, schedule::ScheduleLabel},
-    prelude::*,
-};
+use bevy::{app::ScheduleRunnerPlugin, ecs::schedule::ScheduleLabel, prelude::*};
 use bevy_sequential_actions::*;
 
 fn main() {
@@ -13,14 +9,17 @@ fn main() {
         .init_schedule(OddSchedule)
         .add_plugins((
             ScheduleRunnerPlugin::run_loop(Duration::from_millis(100)),
-            // Add custom plugin for the even schedule
-            CustomSequentialActionsPlugin::new(EvenSchedule)
-                .with_cleanup()
-                .with_filter::<With<EvenMarker>>(),
-            // Add custom plugin for the odd schedule
-            CustomSequentialActionsPlugin::new(OddSchedule)
+            // Add plugin for the even schedule
+            SequentialActionsPlugin::new()
+                .in_schedule(EvenSchedule)
+                .with_filter::<With<EvenMarker>>()
+                .with_cleanup(CleanupMode::Observers),
+            // Add plugin for the odd schedule
+            SequentialActionsPlugin::new()
+                .in_schedule(OddSchedule)
+                .with_filter::<With<OddMarker>>()
                 // No cleanup for odd agents
-                .with_filter::<With<OddMarker>>(),
+                .with_cleanup(CleanupMode::None),
         ))
         .add_systems(Startup, setup)
         .add_systems(Update, run_custom_schedules)
@@ -62,7 +61,7 @@ fn run_custom_schedules(
     mut frame_count: Local<u32>,
     mut agent_q: Local<QueryState<Entity, With<SequentialActions>>>,
 ) {
-    if *frame_count % 2 == 0 {
+    if frame_count.is_multiple_of(2) {
         world.run_schedule(EvenSchedule);
     } else {
         world.run_schedule(OddSchedule);
@@ -107,83 +106,3 @@ impl Action for PrintForeverAction {
         println!("Dropping action for agent {}...", self.agent);
     }
 }
-
-/// Custom plugin for sequential actions.
-///
-/// Action queue advancement will run in the specified schedule `S`,
-/// and only for agents matching the specified query filter `F`.
-/// With `cleanup` enabled, an observer will trigger for despawned agents
-/// that ensures any remaining action is cleaned up.
-struct CustomSequentialActionsPlugin<S: ScheduleLabel, F: QueryFilter> {
-    schedule: S,
-    cleanup: bool,
-    filter: PhantomData<F>,
-}
-
-impl<S: ScheduleLabel> CustomSequentialActionsPlugin<S, ()> {
-    const fn new(schedule: S) -> Self {
-        Self {
-            schedule,
-            cleanup: false,
-            filter: PhantomData,
-        }
-    }
-
-    const fn with_cleanup(mut self) -> Self {
-        self.cleanup = true;
-        self
-    }
-
-    fn with_filter<F: QueryFilter>(self) -> CustomSequentialActionsPlugin<S, F> {
-        CustomSequentialActionsPlugin {
-            schedule: self.schedule,
-            cleanup: self.cleanup,
-            filter: PhantomData,
-        }
-    }
-}
-
-impl<S: ScheduleLabel, F: QueryFilter> CustomSequentialActionsPlugin<S, F> {
-    fn check_actions_exclusive(
-        world: &mut World,
-        mut finished: Local<Vec<Entity>>,
-        mut agent_q: Local<QueryState<(Entity, &CurrentAction), F>>,
-    ) {
-        // Collect all agents with finished action
-        finished.extend(agent_q.iter(world).filter_map(|(agent, current_action)| {
-            current_action
-                .as_ref()
-                .and_then(|action| action.is_finished(agent, world).then_some(agent))
-        }));
-
-        // Do something with the finished list if you want.
-        // Perhaps sort by some identifier for deterministic behavior.
-
-        // Advance the action queue
-        for agent in finished.drain(..) {
-            SequentialActionsPlugin::stop_current_action(agent, StopReason::Finished, world);
-            SequentialActionsPlugin::start_next_action(agent, world);
-        }
-    }
-}
-
-impl Default for CustomSequentialActionsPlugin<Last, ()> {
-    fn default() -> Self {
-        Self::new(Last).with_cleanup()
-    }
-}
-
-impl<S: ScheduleLabel + Clone, F: QueryFilter + Send + Sync + 'static> Plugin
-    for CustomSequentialActionsPlugin<S, F>
-{
-    fn build(&self, app: &mut App) {
-        // Add system for advancing action queue to specified schedule
-        app.add_systems(self.schedule.clone(), Self::check_actions_exclusive);
-
-        // Add observers for cleanup of actions when despawning agents
-        if self.cleanup {
-            app.add_observer(CurrentAction::on_remove_trigger::<F>)
-                .add_observer(ActionQueue::on_remove_trigger::<F>);
-        }
-    }
-}
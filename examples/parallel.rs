@@ -1,10 +1,14 @@
 use bevy::prelude::*;
 use bevy_sequential_actions::*;
-use shared::{CountdownAction, ParallelActions, PrintAction, SharedActionsPlugin, WaitAction};
+use shared::{CountdownAction, PrintAction, SharedActionsPlugin, WaitAction};
 
 fn main() {
     App::new()
-        .add_plugins((MinimalPlugins, SequentialActionsPlugin, SharedActionsPlugin))
+        .add_plugins((
+            MinimalPlugins,
+            SequentialActionsPlugin::default(),
+            SharedActionsPlugin,
+        ))
         .add_systems(Startup, setup)
         .run();
 }
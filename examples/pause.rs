@@ -8,7 +8,7 @@ fn main() {
     App::new()
         .add_plugins((
             MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_millis(100))),
-            SequentialActionsPlugin,
+            SequentialActionsPlugin::default(),
             SharedActionsPlugin,
         ))
         .add_systems(Startup, setup)
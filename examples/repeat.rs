@@ -1,10 +1,13 @@
 use bevy::{app::ScheduleRunnerPlugin, prelude::*};
 use bevy_sequential_actions::*;
-use shared::{PrintAction, RepeatAction};
+use shared::PrintAction;
 
 fn main() {
     App::new()
-        .add_plugins((ScheduleRunnerPlugin::default(), SequentialActionsPlugin))
+        .add_plugins((
+            ScheduleRunnerPlugin::default(),
+            SequentialActionsPlugin::default(),
+        ))
         .add_systems(Startup, setup)
         .run();
 }
@@ -1,10 +1,13 @@
 use bevy::{app::ScheduleRunnerPlugin, prelude::*};
 use bevy_sequential_actions::*;
-use shared::{ActionSequence, PrintAction, RepeatActionSequence};
+use shared::PrintAction;
 
 fn main() {
     App::new()
-        .add_plugins((ScheduleRunnerPlugin::default(), SequentialActionsPlugin))
+        .add_plugins((
+            ScheduleRunnerPlugin::default(),
+            SequentialActionsPlugin::default(),
+        ))
         .add_systems(Startup, setup)
         .run();
 }
@@ -21,14 +24,14 @@ fn setup(mut commands: Commands) {
             PrintAction::new("cowboy"),
         ]))
         .add(PrintAction::new("\n------\n"))
-        .add(RepeatActionSequence::new(
-            actions![
+        .add(
+            ActionSequence::new(actions![
                 PrintAction::new("1"),
                 PrintAction::new("2"),
                 PrintAction::new("3"),
-            ],
-            1,
-        ))
+            ])
+            .repeat(1),
+        )
         .add(|_agent, world: &mut World| -> bool {
             world.write_message(AppExit::Success);
             true
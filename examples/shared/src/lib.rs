@@ -1,17 +1,11 @@
 use bevy::prelude::*;
 
 mod countdown;
-mod parallel;
 mod print;
-mod repeat;
-mod sequence;
 mod wait;
 
 pub use countdown::*;
-pub use parallel::*;
 pub use print::*;
-pub use repeat::*;
-pub use sequence::*;
 pub use wait::*;
 
 pub struct SharedActionsPlugin;
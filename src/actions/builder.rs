@@ -0,0 +1,160 @@
+use super::*;
+
+type OnAdd = Box<dyn FnMut(Entity, &mut World) + Send + Sync>;
+type OnRemove = Box<dyn FnMut(Option<Entity>, &mut World) + Send + Sync>;
+type OnDrop = Box<dyn FnOnce(Option<Entity>, &mut World, DropReason) + Send + Sync>;
+
+/// A builder for creating an [`Action`] from closures.
+///
+/// Each closure is optional, and an action without [`is_finished`](Self::is_finished)
+/// is finished as soon as it has started.
+/// Give the action a [`name`](Self::named) to make it easier to identify,
+/// as it is used as the [`type name`](Action::type_name) of the action.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Opened;
+/// #
+/// fn setup(mut commands: Commands) {
+///     let agent = commands.spawn(SequentialActions).id();
+///     commands.actions(agent).add(
+///         ActionBuilder::new()
+///             .on_start(|agent, world: &mut World| println!("Agent {agent} is opening chest."))
+///             .is_finished(|agent, world: &World| world.entity(agent).contains::<Opened>())
+///             .on_stop(|agent, world: &mut World, reason| println!("Stopped with {reason:?}."))
+///             .named("OpenChest")
+///             .build(),
+///     );
+/// }
+/// ```
+#[derive(Default)]
+#[must_use]
+pub struct ActionBuilder {
+    on_add: Option<OnAdd>,
+    on_start: Option<OnStart>,
+    is_finished: Option<Condition>,
+    on_stop: Option<OnStop>,
+    on_remove: Option<OnRemove>,
+    on_drop: Option<OnDrop>,
+    name: Option<&'static str>,
+}
+
+impl ActionBuilder {
+    /// Creates a new builder without any closures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `f` when the action is [`added`](Action::on_add).
+    pub fn on_add(mut self, f: impl FnMut(Entity, &mut World) + Send + Sync + 'static) -> Self {
+        self.on_add = Some(Box::new(f));
+        self
+    }
+
+    /// Calls `f` when the action is [`started`](Action::on_start).
+    pub fn on_start(mut self, f: impl FnMut(Entity, &mut World) + Send + Sync + 'static) -> Self {
+        self.on_start = Some(Box::new(f));
+        self
+    }
+
+    /// Uses `f` for determining if the action is [`finished`](Action::is_finished).
+    pub fn is_finished(
+        mut self,
+        f: impl Fn(Entity, &World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.is_finished = Some(Box::new(f));
+        self
+    }
+
+    /// Calls `f` when the action is [`stopped`](Action::on_stop).
+    pub fn on_stop(
+        mut self,
+        f: impl FnMut(Option<Entity>, &mut World, StopReason) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_stop = Some(Box::new(f));
+        self
+    }
+
+    /// Calls `f` when the action is [`removed`](Action::on_remove).
+    pub fn on_remove(
+        mut self,
+        f: impl FnMut(Option<Entity>, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_remove = Some(Box::new(f));
+        self
+    }
+
+    /// Calls `f` when the action is [`dropped`](Action::on_drop).
+    pub fn on_drop(
+        mut self,
+        f: impl FnOnce(Option<Entity>, &mut World, DropReason) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_drop = Some(Box::new(f));
+        self
+    }
+
+    /// Uses `name` as the [`type name`](Action::type_name) of the action.
+    pub fn named(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Builds the action.
+    pub fn build(self) -> BoxedAction {
+        Box::new(BuiltAction(self))
+    }
+}
+
+struct BuiltAction(ActionBuilder);
+
+impl Action for BuiltAction {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.0
+            .is_finished
+            .as_ref()
+            .is_none_or(|is_finished| is_finished(agent, world))
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        if let Some(on_add) = &mut self.0.on_add {
+            on_add(agent, world);
+        }
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        if let Some(on_start) = &mut self.0.on_start {
+            on_start(agent, world);
+        }
+
+        self.is_finished(agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        if let Some(on_stop) = &mut self.0.on_stop {
+            on_stop(agent, world, reason);
+        }
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        if let Some(on_remove) = &mut self.0.on_remove {
+            on_remove(agent, world);
+        }
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        if let Some(on_drop) = self.0.on_drop {
+            on_drop(agent, world, reason);
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.0
+            .name
+            .unwrap_or_else(|| std::any::type_name::<ActionBuilder>())
+    }
+}
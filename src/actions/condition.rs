@@ -0,0 +1,140 @@
+use super::*;
+
+/// An action that runs one of two actions based on a condition.
+///
+/// The condition is evaluated when the action is first [`started`](Action::on_start),
+/// and the chosen action is kept when paused and resumed.
+///
+/// Named `IfElse` to avoid clashing with the [`If`](bevy_ecs::system::If) system parameter.
+///
+/// Both actions are [`added`](Action::on_add) and [`removed`](Action::on_remove) together with this action,
+/// while the action that was not chosen is [`dropped`](Action::on_drop) as [`skipped`](DropReason::Skipped).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Hungry;
+/// # struct Eat;
+/// # impl Action for Eat {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// # struct Sleep;
+/// # impl Action for Sleep {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// #
+/// fn setup(mut commands: Commands) {
+///     let agent = commands.spawn(SequentialActions).id();
+///     commands.actions(agent).add(IfElse::new(
+///         |agent, world: &World| world.entity(agent).contains::<Hungry>(),
+///         Eat,
+///         Sleep,
+///     ));
+/// }
+/// ```
+pub struct IfElse {
+    cond: Condition,
+    then: BoxedAction,
+    otherwise: BoxedAction,
+    branch: Option<bool>,
+}
+
+impl IfElse {
+    /// Creates a new action that runs `then` if `cond` returns `true`, or else `otherwise`.
+    pub fn new(
+        cond: impl Fn(Entity, &World) -> bool + Send + Sync + 'static,
+        then: impl IntoBoxedAction,
+        otherwise: impl IntoBoxedAction,
+    ) -> Self {
+        Self {
+            cond: Box::new(cond),
+            then: then.into_boxed_action(),
+            otherwise: otherwise.into_boxed_action(),
+            branch: None,
+        }
+    }
+
+    /// Returns the evaluated condition, or `None` if the action has not been started yet.
+    pub fn branch(&self) -> Option<bool> {
+        self.branch
+    }
+
+    fn chosen(&self) -> Option<&BoxedAction> {
+        self.branch
+            .map(|branch| if branch { &self.then } else { &self.otherwise })
+    }
+
+    fn chosen_mut(&mut self) -> Option<&mut BoxedAction> {
+        match self.branch? {
+            true => Some(&mut self.then),
+            false => Some(&mut self.otherwise),
+        }
+    }
+}
+
+impl Action for IfElse {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        self.chosen()
+            .map_or(ActionStatus::Succeeded, |action| action.poll(agent, world))
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        if let Some(action) = self.chosen_mut() {
+            action.on_step(agent, world);
+        }
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.branch = None;
+        self.then.on_add(agent, world);
+        self.otherwise.on_add(agent, world);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        if self.branch.is_none() {
+            self.branch = Some((self.cond)(agent, world));
+        }
+
+        self.chosen_mut()
+            .is_none_or(|action| action.on_start(agent, world))
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        if let Some(action) = self.chosen_mut() {
+            action.on_stop(agent, world, reason);
+        }
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.then.on_remove(agent, world);
+        self.otherwise.on_remove(agent, world);
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        let skipped = match reason {
+            DropReason::Done | DropReason::Failed | DropReason::TimedOut => DropReason::Skipped,
+            reason => reason,
+        };
+
+        let (then, otherwise) = match self.branch {
+            Some(true) => (reason, skipped),
+            Some(false) => (skipped, reason),
+            None => (skipped, skipped),
+        };
+
+        self.then.on_drop(agent, world, then);
+        self.otherwise.on_drop(agent, world, otherwise);
+    }
+}
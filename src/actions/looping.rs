@@ -0,0 +1,213 @@
+use super::*;
+
+/// An action that repeatedly runs a freshly created action while a condition is `true`.
+///
+/// The condition is evaluated before each iteration.
+/// The action finishes when the condition is `false`, and fails if the created action fails.
+///
+/// Looping happens by [`stepping`](Action::on_step) to the next iteration within this action,
+/// so it behaves the same whether it is in the queue or nested inside another action.
+/// Note that an action that finishes instantly will loop within the same frame
+/// for as long as the condition is `true`.
+pub struct While(Loop);
+
+impl While {
+    /// Creates a new action that runs the action created by `factory` while `cond` returns `true`.
+    pub fn new<A: IntoBoxedAction>(
+        cond: impl Fn(Entity, &World) -> bool + Send + Sync + 'static,
+        factory: impl FnMut() -> A + Send + Sync + 'static,
+    ) -> Self {
+        Self(Loop::new(cond, factory, false))
+    }
+
+    /// Returns the number of started iterations.
+    pub fn iterations(&self) -> u32 {
+        self.0.iterations
+    }
+}
+
+/// An action that repeatedly runs a freshly created action until a condition is `true`.
+///
+/// Unlike [`While`], the created action is always run at least once,
+/// as the condition is first evaluated after the first iteration.
+/// The action finishes when the condition is `true`, and fails if the created action fails.
+///
+/// Looping happens by [`stepping`](Action::on_step) to the next iteration within this action,
+/// so it behaves the same whether it is in the queue or nested inside another action.
+/// Note that an action that finishes instantly will loop within the same frame
+/// for as long as the condition is `false`.
+pub struct RepeatUntil(Loop);
+
+impl RepeatUntil {
+    /// Creates a new action that runs the action created by `factory` until `cond` returns `true`.
+    pub fn new<A: IntoBoxedAction>(
+        factory: impl FnMut() -> A + Send + Sync + 'static,
+        cond: impl Fn(Entity, &World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self(Loop::new(cond, factory, true))
+    }
+
+    /// Returns the number of started iterations.
+    pub fn iterations(&self) -> u32 {
+        self.0.iterations
+    }
+}
+
+struct Loop {
+    cond: Condition,
+    factory: Factory,
+    body: Option<BoxedAction>,
+    until: bool,
+    iterations: u32,
+    finished: bool,
+}
+
+impl Loop {
+    fn new<A: IntoBoxedAction>(
+        cond: impl Fn(Entity, &World) -> bool + Send + Sync + 'static,
+        mut factory: impl FnMut() -> A + Send + Sync + 'static,
+        until: bool,
+    ) -> Self {
+        Self {
+            cond: Box::new(cond),
+            factory: Box::new(move || factory().into_boxed_action()),
+            body: None,
+            until,
+            iterations: 0,
+            finished: false,
+        }
+    }
+
+    /// Finishes the current iteration if any, and starts new iterations
+    /// until one is not immediately finished.
+    /// Returns `true` if the loop has finished.
+    fn next(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.finished = false;
+
+        loop {
+            if let Some(mut body) = self.body.take() {
+                body.on_stop(Some(agent), world, StopReason::Finished);
+                body.on_remove(Some(agent), world);
+                body.on_drop(Some(agent), world, DropReason::Done);
+            }
+
+            // Skip the condition on the first iteration when repeating until.
+            let check = !self.until || self.iterations > 0;
+            if check && (self.cond)(agent, world) == self.until {
+                self.finished = true;
+                return true;
+            }
+
+            let body = self.body.insert((self.factory)());
+            body.on_add(agent, world);
+            self.iterations += 1;
+
+            if !body.on_start(agent, world) {
+                return false;
+            }
+        }
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        if self.finished {
+            return ActionStatus::Succeeded;
+        }
+
+        match self.body.as_ref().map(|body| body.poll(agent, world)) {
+            // Not done looping, so step to the next iteration.
+            Some(ActionStatus::Succeeded) => ActionStatus::Step,
+            Some(status) => status,
+            None => ActionStatus::Succeeded,
+        }
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        let Some(body) = &mut self.body else {
+            return;
+        };
+
+        if step(body, agent, world) == ActionStatus::Succeeded {
+            self.next(agent, world);
+        }
+    }
+
+    fn on_add(&mut self) {
+        self.iterations = 0;
+        self.finished = false;
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        // Resume the current iteration, if any.
+        if let Some(body) = &mut self.body
+            && !body.on_start(agent, world)
+        {
+            return false;
+        }
+
+        self.next(agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        if let Some(body) = &mut self.body {
+            body.on_stop(agent, world, reason);
+        }
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        if let Some(body) = &mut self.body {
+            body.on_remove(agent, world);
+        }
+    }
+
+    fn on_drop(&mut self, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        if let Some(body) = self.body.take() {
+            body.on_drop(agent, world, reason);
+        }
+    }
+}
+
+macro_rules! impl_loop_action {
+    ($T:ident) => {
+        impl Action for $T {
+            fn is_finished(&self, agent: Entity, world: &World) -> bool {
+                self.poll(agent, world).is_finished()
+            }
+
+            fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+                self.0.poll(agent, world)
+            }
+
+            fn on_step(&mut self, agent: Entity, world: &mut World) {
+                self.0.on_step(agent, world);
+            }
+
+            fn on_add(&mut self, _agent: Entity, _world: &mut World) {
+                self.0.on_add();
+            }
+
+            fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+                self.0.on_start(agent, world)
+            }
+
+            fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+                self.0.on_stop(agent, world, reason);
+            }
+
+            fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+                self.0.on_remove(agent, world);
+            }
+
+            fn on_drop(
+                mut self: Box<Self>,
+                agent: Option<Entity>,
+                world: &mut World,
+                reason: DropReason,
+            ) {
+                self.0.on_drop(agent, world, reason);
+            }
+        }
+    };
+}
+
+impl_loop_action!(While);
+impl_loop_action!(RepeatUntil);
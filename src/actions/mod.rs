@@ -0,0 +1,208 @@
+use std::{
+    sync::atomic::{AtomicU8, Ordering},
+    time::Duration,
+};
+
+use bevy_time::Time;
+
+use super::*;
+
+mod builder;
+mod condition;
+mod looping;
+mod parallel;
+mod repeat;
+mod retry;
+mod select;
+mod sequence;
+mod system;
+mod then;
+mod timeout;
+mod wait;
+
+pub use builder::*;
+pub use condition::*;
+pub use looping::*;
+pub use parallel::*;
+pub use repeat::*;
+pub use retry::*;
+pub use select::*;
+pub use sequence::*;
+pub use system::*;
+pub use then::*;
+pub use timeout::*;
+pub use wait::*;
+
+/// A boxed condition evaluated for an `agent`.
+pub(crate) type Condition = Box<dyn Fn(Entity, &World) -> bool + Send + Sync>;
+
+/// A boxed factory for creating new actions.
+pub(crate) type Factory = Box<dyn FnMut() -> BoxedAction + Send + Sync>;
+
+/// A boxed closure called when an action is started.
+pub(crate) type OnStart = Box<dyn FnMut(Entity, &mut World) + Send + Sync>;
+
+/// A boxed closure called when an action is stopped.
+pub(crate) type OnStop = Box<dyn FnMut(Option<Entity>, &mut World, StopReason) + Send + Sync>;
+
+/// [`Steps`](Action::on_step) `action` if needed, and returns its [`status`](ActionStatus) afterwards.
+///
+/// This way, actions running other actions in steps can advance to their next step
+/// within the same frame as the current one has finished.
+fn step(action: &mut BoxedAction, agent: Entity, world: &mut World) -> ActionStatus {
+    match action.poll(agent, world) {
+        ActionStatus::Step => {
+            action.on_step(agent, world);
+            action.poll(agent, world)
+        }
+        status => status,
+    }
+}
+
+/// Returns the elapsed time of the [`Time`] resource.
+fn elapsed(world: &World) -> Option<Duration> {
+    world.get_resource::<Time>().map(Time::elapsed)
+}
+
+/// A child action that is run alongside other child actions.
+///
+/// The [`status`](ActionStatus) of the child is latched when it has finished,
+/// so that it is remembered until the parent action is stopped.
+pub(crate) struct Child {
+    action: BoxedAction,
+    status: AtomicU8,
+    stopped: bool,
+    restart: bool,
+}
+
+impl Child {
+    const RUNNING: u8 = 0;
+    const SUCCEEDED: u8 = 1;
+    const FAILED: u8 = 2;
+    const TIMED_OUT: u8 = 3;
+
+    pub(crate) fn new(action: BoxedAction) -> Self {
+        Self {
+            action,
+            status: AtomicU8::new(Self::RUNNING),
+            stopped: false,
+            restart: false,
+        }
+    }
+
+    pub(crate) fn action(&self) -> &dyn Action {
+        self.action.as_ref()
+    }
+
+    /// Returns the latched status of the child.
+    pub(crate) fn status(&self) -> ActionStatus {
+        match self.status.load(Ordering::Relaxed) {
+            Self::SUCCEEDED => ActionStatus::Succeeded,
+            Self::FAILED => ActionStatus::Failed,
+            Self::TIMED_OUT => ActionStatus::TimedOut,
+            _ => ActionStatus::Running,
+        }
+    }
+
+    fn latch(&self, status: ActionStatus) {
+        let value = match status {
+            ActionStatus::Running | ActionStatus::Step => return,
+            ActionStatus::Succeeded => Self::SUCCEEDED,
+            ActionStatus::Failed => Self::FAILED,
+            ActionStatus::TimedOut => Self::TIMED_OUT,
+        };
+        self.status.store(value, Ordering::Relaxed);
+    }
+
+    /// Polls the child if it is still running, and latches the result.
+    pub(crate) fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        let status = self.status();
+        if status != ActionStatus::Running || self.stopped {
+            return status;
+        }
+
+        let status = self.action.poll(agent, world);
+        self.latch(status);
+        status
+    }
+
+    /// [`Steps`](Action::on_step) the child if it is still running and needs to step,
+    /// and returns its status afterwards.
+    pub(crate) fn step(&mut self, agent: Entity, world: &mut World) -> ActionStatus {
+        if self.poll(agent, world) == ActionStatus::Step {
+            self.action.on_step(agent, world);
+        }
+
+        self.poll(agent, world)
+    }
+
+    /// Adds the child, and resets any latched status.
+    pub(crate) fn add(&mut self, agent: Entity, world: &mut World) {
+        *self.status.get_mut() = Self::RUNNING;
+        self.stopped = false;
+        self.restart = false;
+        self.action.on_add(agent, world);
+    }
+
+    /// Starts the child if it has not been stopped.
+    ///
+    /// If the parent is started again after being stopped for any reason other than
+    /// [`paused`](StopReason::Paused), such as when retried with [`OnFailure::Retry`],
+    /// the latched status is reset and the child is started over.
+    pub(crate) fn start(&mut self, agent: Entity, world: &mut World) {
+        if std::mem::take(&mut self.restart) {
+            *self.status.get_mut() = Self::RUNNING;
+            self.stopped = false;
+        }
+
+        if self.stopped || self.status() != ActionStatus::Running {
+            return;
+        }
+
+        if self.action.on_start(agent, world) {
+            self.latch(ActionStatus::Succeeded);
+        }
+    }
+
+    /// Stops the child based on its latched status and the `reason` of the parent.
+    ///
+    /// Finished children are stopped as either finished or failed,
+    /// while running children are canceled when the parent is done.
+    pub(crate) fn stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        self.restart = reason != StopReason::Paused;
+
+        if self.stopped {
+            return;
+        }
+
+        let reason = match (self.status(), reason) {
+            (ActionStatus::Succeeded, _) => StopReason::Finished,
+            (ActionStatus::Failed, _) => StopReason::Failed,
+            (ActionStatus::TimedOut, _) => StopReason::TimedOut,
+            (
+                ActionStatus::Running | ActionStatus::Step,
+                StopReason::Finished | StopReason::Failed,
+            ) => StopReason::Canceled,
+            (ActionStatus::Running | ActionStatus::Step, reason) => reason,
+        };
+
+        self.action.on_stop(agent, world, reason);
+        self.stopped = reason != StopReason::Paused;
+    }
+
+    pub(crate) fn remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.action.on_remove(agent, world);
+    }
+
+    /// Drops the child based on its latched status and the `reason` of the parent.
+    pub(crate) fn drop(self, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        let reason = match (self.status(), reason) {
+            (_, DropReason::Skipped | DropReason::Cleared | DropReason::Replaced) => reason,
+            (ActionStatus::Failed, _) => DropReason::Failed,
+            (ActionStatus::TimedOut, _) => DropReason::TimedOut,
+            _ => DropReason::Done,
+        };
+
+        self.action.on_drop(agent, world, reason);
+    }
+}
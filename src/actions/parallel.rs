@@ -0,0 +1,98 @@
+use super::*;
+
+/// An action that runs multiple actions in parallel.
+///
+/// The action is finished when all actions have finished,
+/// regardless of whether they finished within the same frame or not.
+/// If any action fails or times out, the remaining actions are [`canceled`](StopReason::Canceled)
+/// and the parallel action fails or times out as well.
+///
+/// Actions that finish early are not [`stopped`](Action::on_stop) right away,
+/// but together with the others once the parallel action stops.
+/// Pausing stops the finished actions and pauses the rest,
+/// so that only the unfinished actions are started again when resumed.
+pub struct ParallelActions {
+    actions: Box<[Child]>,
+}
+
+impl ParallelActions {
+    /// Creates a new action that runs `actions` in parallel.
+    pub fn new(actions: impl IntoBoxedActions) -> Self {
+        Self {
+            actions: actions.into_boxed_actions().map(Child::new).collect(),
+        }
+    }
+
+    /// Returns the number of actions.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Returns `true` if there are no actions.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+impl Action for ParallelActions {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        let mut status = ActionStatus::Succeeded;
+
+        for action in &self.actions {
+            match action.poll(agent, world) {
+                status @ (ActionStatus::Failed | ActionStatus::TimedOut) => return status,
+                ActionStatus::Step => status = ActionStatus::Step,
+                ActionStatus::Running if status != ActionStatus::Step => {
+                    status = ActionStatus::Running
+                }
+                ActionStatus::Running | ActionStatus::Succeeded => {}
+            }
+        }
+
+        status
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        for action in &mut self.actions {
+            action.step(agent, world);
+        }
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.add(agent, world));
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.start(agent, world));
+
+        self.actions
+            .iter()
+            .all(|action| action.status() == ActionStatus::Succeeded)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.stop(agent, world, reason));
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.remove(agent, world));
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        self.actions
+            .into_iter()
+            .for_each(|action| action.drop(agent, world, reason));
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+/// An action that repeats another action by a specified amount.
+///
+/// Once the action has finished, it is [`removed`](Action::on_remove),
+/// [`added`](Action::on_add) and [`started`](Action::on_start) again
+/// by [`stepping`](Action::on_step) within this action,
+/// so it behaves the same whether it is in the queue or nested inside another action.
+/// The action is [`dropped`](Action::on_drop) once there are no more repeats,
+/// or when it is canceled, fails, or is skipped or cleared.
+pub struct RepeatAction {
+    action: BoxedAction,
+    repeat: u32,
+    remaining: u32,
+    finished: bool,
+}
+
+impl RepeatAction {
+    /// Creates a new action that runs `action` an additional `repeat` times.
+    pub fn new(action: impl IntoBoxedAction, repeat: u32) -> Self {
+        Self {
+            action: action.into_boxed_action(),
+            repeat,
+            remaining: repeat,
+            finished: false,
+        }
+    }
+
+    /// Returns the number of remaining repeats.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Repeats the finished action until it is not immediately finished again,
+    /// and returns `true` if there are no more repeats.
+    fn repeat_finished(&mut self, agent: Entity, world: &mut World) -> bool {
+        while self.remaining > 0 {
+            self.remaining -= 1;
+            self.action
+                .on_stop(Some(agent), world, StopReason::Finished);
+            self.action.on_remove(Some(agent), world);
+            self.action.on_add(agent, world);
+
+            if !self.action.on_start(agent, world) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Action for RepeatAction {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        if self.finished {
+            return ActionStatus::Succeeded;
+        }
+
+        match self.action.poll(agent, world) {
+            ActionStatus::Succeeded if self.remaining > 0 => ActionStatus::Step,
+            status => status,
+        }
+    }
+
+    fn is_polled(&self) -> bool {
+        self.action.is_polled()
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        if step(&mut self.action, agent, world) == ActionStatus::Succeeded {
+            self.finished = self.repeat_finished(agent, world);
+        }
+    }
+
+    fn on_finish(&mut self, agent: Entity, world: &mut World) -> bool {
+        if self.action.on_finish(agent, world) {
+            self.finished = self.repeat_finished(agent, world);
+        }
+
+        self.finished
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.remaining = self.repeat;
+        self.finished = false;
+        self.action.on_add(agent, world);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.action.on_start(agent, world) && self.repeat_finished(agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        self.action.on_stop(agent, world, reason);
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.action.on_remove(agent, world);
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        self.action.on_drop(agent, world, reason);
+    }
+}
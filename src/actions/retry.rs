@@ -0,0 +1,205 @@
+use super::*;
+
+/// An action that retries a freshly created action when it fails,
+/// up to a specified amount of attempts.
+///
+/// Each attempt creates a new action using the factory,
+/// and the previous action is [`stopped`](Action::on_stop) and [`dropped`](Action::on_drop) as failed.
+/// Only the last attempt is reported as [`failed`](ActionStatus::Failed),
+/// so the [`OnFailure`] policy of the `agent` applies once all attempts are used.
+///
+/// Retrying happens by [`stepping`](Action::on_step) to the next attempt within this action,
+/// so it behaves the same whether it is in the queue or nested inside another action.
+pub struct Retry {
+    factory: Factory,
+    action: Option<Child>,
+    attempt: u32,
+    attempts: u32,
+    backoff: Duration,
+    waiting: Option<Duration>,
+    deadline: Option<Duration>,
+}
+
+impl Retry {
+    /// Creates a new action that runs the action created by `factory`,
+    /// and retries with a new action when it fails up to a total of `attempts` times.
+    ///
+    /// There is always at least one attempt.
+    pub fn new<A: IntoBoxedAction>(
+        mut factory: impl FnMut() -> A + Send + Sync + 'static,
+        attempts: u32,
+    ) -> Self {
+        Self {
+            factory: Box::new(move || factory().into_boxed_action()),
+            action: None,
+            attempt: 1,
+            attempts: attempts.max(1),
+            backoff: Duration::ZERO,
+            waiting: None,
+            deadline: None,
+        }
+    }
+
+    /// Waits for `backoff` between each attempt.
+    ///
+    /// The elapsed time is measured using the [`Time`] resource,
+    /// and the remaining time is kept when paused.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Returns the current attempt, starting from `1`.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the total amount of attempts.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns `true` if waiting for the next attempt.
+    pub fn is_waiting(&self) -> bool {
+        self.waiting.is_some()
+    }
+
+    /// Waits for the `remaining` time before the next attempt.
+    fn wait(&mut self, remaining: Duration, agent: Entity, world: &World) {
+        self.waiting = Some(remaining);
+
+        match elapsed(world) {
+            Some(elapsed) => self.deadline = Some(elapsed + remaining),
+            None => warn!(
+                "Cannot wait before next attempt for agent {agent} due to missing resource {}.",
+                std::any::type_name::<Time>()
+            ),
+        }
+    }
+
+    /// Creates, adds and starts the action for the next attempt.
+    fn start_attempt(&mut self, agent: Entity, world: &mut World) {
+        let mut action = Child::new((self.factory)());
+        action.add(agent, world);
+        action.start(agent, world);
+        self.action = Some(action);
+    }
+}
+
+impl Action for Retry {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        if self.waiting.is_some() {
+            return match (self.deadline, elapsed(world)) {
+                (Some(deadline), Some(elapsed)) if elapsed < deadline => ActionStatus::Running,
+                _ => ActionStatus::Step,
+            };
+        }
+
+        let Some(action) = &self.action else {
+            return ActionStatus::Succeeded;
+        };
+
+        match action.poll(agent, world) {
+            // Not the last attempt, so step to the next one.
+            ActionStatus::Failed | ActionStatus::TimedOut if self.attempt < self.attempts => {
+                ActionStatus::Step
+            }
+            status => status,
+        }
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        if self.waiting.take().is_some() {
+            // Done waiting, so start the next attempt.
+            self.deadline = None;
+            self.start_attempt(agent, world);
+            return;
+        }
+
+        let Some(action) = &mut self.action else {
+            return;
+        };
+
+        match action.step(agent, world) {
+            ActionStatus::Failed | ActionStatus::TimedOut if self.attempt < self.attempts => {
+                // Drop the failed action, and either wait or start the next attempt.
+                if let Some(mut action) = self.action.take() {
+                    action.stop(Some(agent), world, StopReason::Failed);
+                    action.remove(Some(agent), world);
+                    action.drop(Some(agent), world, DropReason::Done);
+                }
+
+                self.attempt += 1;
+                if self.backoff.is_zero() {
+                    self.start_attempt(agent, world);
+                } else {
+                    self.wait(self.backoff, agent, world);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        // Start over with a fresh action when added again.
+        if let Some(action) = self.action.take() {
+            action.drop(Some(agent), world, DropReason::Done);
+        }
+
+        self.attempt = 1;
+        self.waiting = None;
+        self.deadline = None;
+
+        let mut action = Child::new((self.factory)());
+        action.add(agent, world);
+        self.action = Some(action);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        if let Some(remaining) = self.waiting {
+            self.wait(remaining, agent, world);
+            return false;
+        }
+
+        let Some(action) = &mut self.action else {
+            return true;
+        };
+
+        action.start(agent, world);
+        action.status() == ActionStatus::Succeeded
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        if self.waiting.is_some() {
+            // Store remaining time when paused.
+            if let (StopReason::Paused, Some(deadline), Some(elapsed)) =
+                (reason, self.deadline, elapsed(world))
+            {
+                self.waiting = Some(deadline.saturating_sub(elapsed));
+            }
+
+            self.deadline = None;
+            return;
+        }
+
+        if let Some(action) = &mut self.action {
+            action.stop(agent, world, reason);
+        }
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        if let Some(action) = &mut self.action {
+            action.remove(agent, world);
+        }
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        if let Some(action) = self.action {
+            action.drop(agent, world, reason);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use super::*;
+
+/// An action that runs multiple actions concurrently,
+/// and finishes when a specified amount of them have finished.
+///
+/// Once enough actions have finished, the remaining actions are [`canceled`](StopReason::Canceled).
+/// The action fails if enough actions have failed or timed out that it can no longer finish.
+/// The actions that finished are the winners, and can be inspected using
+/// [`winners`](Self::winners) and [`winner_as`](Self::winner_as).
+/// Once the select action has finished, the indices of the winners are its
+/// [`output`](ActionWithOutput), so they can be used with [`then`](ActionWithOutput::then).
+///
+/// Like [`ParallelActions`], actions that finish early are not [`stopped`](Action::on_stop) right away,
+/// but together with the others once the select action stops.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # struct WalkToDoor;
+/// # impl Action for WalkToDoor {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// # struct Wait(f32);
+/// # impl Action for Wait {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// #
+/// fn setup(mut commands: Commands) {
+///     let agent = commands.spawn(SequentialActions).id();
+///     // Walk to the door, or wait 5 seconds, whichever comes first.
+///     commands
+///         .actions(agent)
+///         .add(SelectActions::race((WalkToDoor, Wait(5.0))));
+/// }
+/// ```
+pub struct SelectActions {
+    actions: Box<[Child]>,
+    count: usize,
+}
+
+impl SelectActions {
+    /// Creates a new action that finishes when `count` of the `actions` have finished.
+    pub fn new(count: usize, actions: impl IntoBoxedActions) -> Self {
+        Self {
+            actions: actions.into_boxed_actions().map(Child::new).collect(),
+            count,
+        }
+    }
+
+    /// Creates a new action that finishes when the first of the `actions` has finished.
+    pub fn race(actions: impl IntoBoxedActions) -> Self {
+        Self::new(1, actions)
+    }
+
+    /// Returns the amount of actions that need to finish.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of actions.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Returns `true` if there are no actions.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Returns the action at `index`.
+    pub fn get(&self, index: usize) -> Option<&dyn Action> {
+        self.actions.get(index).map(Child::action)
+    }
+
+    /// Returns an iterator over the indices of the actions that have finished.
+    pub fn winners(&self) -> impl Iterator<Item = usize> + '_ {
+        self.actions
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| action.status() == ActionStatus::Succeeded)
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the first action that has finished.
+    pub fn winner(&self) -> Option<usize> {
+        self.winners().next()
+    }
+
+    /// Returns the first action that has finished if it is of type `T`.
+    pub fn winner_as<T: Action>(&self) -> Option<&T> {
+        self.winner()
+            .and_then(|index| self.actions[index].action().downcast_ref::<T>())
+    }
+
+    fn status(&self) -> ActionStatus {
+        let (succeeded, running) =
+            self.actions
+                .iter()
+                .fold((0, 0), |(succeeded, running), action| {
+                    match action.status() {
+                        ActionStatus::Succeeded => (succeeded + 1, running),
+                        ActionStatus::Running | ActionStatus::Step => (succeeded, running + 1),
+                        ActionStatus::Failed | ActionStatus::TimedOut => (succeeded, running),
+                    }
+                });
+
+        if succeeded >= self.count {
+            ActionStatus::Succeeded
+        } else if succeeded + running < self.count {
+            ActionStatus::Failed
+        } else {
+            ActionStatus::Running
+        }
+    }
+}
+
+impl Action for SelectActions {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        // Poll every action so that all finished ones are latched.
+        let step = self
+            .actions
+            .iter()
+            .filter(|action| action.poll(agent, world) == ActionStatus::Step)
+            .count()
+            > 0;
+
+        match self.status() {
+            ActionStatus::Running if step => ActionStatus::Step,
+            status => status,
+        }
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        for action in &mut self.actions {
+            action.step(agent, world);
+        }
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.add(agent, world));
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.start(agent, world));
+
+        self.status() == ActionStatus::Succeeded
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.stop(agent, world, reason));
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.remove(agent, world));
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        self.actions
+            .into_iter()
+            .for_each(|action| action.drop(agent, world, reason));
+    }
+}
+
+impl ActionWithOutput for SelectActions {
+    type Output = Vec<usize>;
+
+    fn take_output(&mut self, _agent: Entity, _world: &mut World) -> Option<Vec<usize>> {
+        (self.status() == ActionStatus::Succeeded).then(|| self.winners().collect())
+    }
+}
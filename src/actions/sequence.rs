@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+/// An action that runs a sequence of actions.
+/// Useful when you want a sequence to act as a single action.
+/// For example, canceling this action will drop the entire sequence.
+///
+/// Each action in the sequence is [`added`](Action::on_add) together with the sequence,
+/// and is [`removed`](Action::on_remove) and [`dropped`](Action::on_drop)
+/// once the entire sequence is done.
+/// Pausing the sequence pauses the current action in the sequence.
+///
+/// The sequence advances by [`stepping`](Action::on_step) to the next action within itself,
+/// so it behaves the same whether it is in the queue or nested inside another action.
+/// Adding the sequence also resets it to the beginning.
+pub struct ActionSequence {
+    actions: Box<[BoxedAction]>,
+    index: usize,
+    repeat: u32,
+    remaining: u32,
+    stop_reason: Option<StopReason>,
+}
+
+impl ActionSequence {
+    /// Creates a new sequence of `actions`.
+    pub fn new(actions: impl IntoBoxedActions) -> Self {
+        Self {
+            actions: actions.into_boxed_actions().collect(),
+            index: 0,
+            repeat: 0,
+            remaining: 0,
+            stop_reason: None,
+        }
+    }
+
+    /// Repeats the sequence an additional `repeat` times.
+    ///
+    /// Each action in the sequence is [`removed`](Action::on_remove)
+    /// and [`added`](Action::on_add) again between each repetition.
+    pub fn repeat(mut self, repeat: u32) -> Self {
+        self.repeat = repeat;
+        self.remaining = repeat;
+        self
+    }
+
+    /// Returns the index of the current action in the sequence.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of actions in the sequence.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Returns `true` if the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Starts the actions from the current index until one is not immediately finished,
+    /// and returns `true` if the entire sequence has finished.
+    fn start_next(&mut self, agent: Entity, world: &mut World) -> bool {
+        loop {
+            let Some(action) = self.actions.get_mut(self.index) else {
+                if self.remaining == 0 || self.actions.is_empty() {
+                    return true;
+                }
+
+                // We have finished the sequence, but are not done with all repeats yet.
+                self.remaining -= 1;
+                self.index = 0;
+                self.actions
+                    .iter_mut()
+                    .for_each(|action| action.on_remove(Some(agent), world));
+                self.actions
+                    .iter_mut()
+                    .for_each(|action| action.on_add(agent, world));
+                continue;
+            };
+
+            if !action.on_start(agent, world) {
+                return false;
+            }
+
+            action.on_stop(Some(agent), world, StopReason::Finished);
+            self.index += 1;
+        }
+    }
+}
+
+impl Action for ActionSequence {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        let Some(action) = self.actions.get(self.index) else {
+            return ActionStatus::Succeeded;
+        };
+
+        match action.poll(agent, world) {
+            // Not the last action, so step to the next one.
+            ActionStatus::Succeeded
+                if self.index + 1 < self.actions.len() || self.remaining > 0 =>
+            {
+                ActionStatus::Step
+            }
+            status => status,
+        }
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        let Some(action) = self.actions.get_mut(self.index) else {
+            return;
+        };
+
+        if step(action, agent, world) == ActionStatus::Succeeded {
+            action.on_stop(Some(agent), world, StopReason::Finished);
+            self.index += 1;
+            self.start_next(agent, world);
+        }
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.index = 0;
+        self.remaining = self.repeat;
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.on_add(agent, world));
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.start_next(agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        if let Some(action) = self.actions.get_mut(self.index) {
+            action.on_stop(agent, world, reason);
+        }
+
+        self.stop_reason = Some(reason);
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        // Actions that have not been reached are skipped,
+        // unless the entire sequence has been skipped, cleared or replaced.
+        let stop_reason = self.stop_reason;
+        let index = match stop_reason {
+            Some(StopReason::Finished) => self.index + 1,
+            _ => self.index,
+        };
+        let rest = match reason {
+            DropReason::Done | DropReason::Failed | DropReason::TimedOut => DropReason::Skipped,
+            reason => reason,
+        };
+
+        for (i, mut action) in self.actions.into_iter().enumerate() {
+            let reason = match (i.cmp(&index), stop_reason) {
+                (Ordering::Less, _) | (Ordering::Equal, Some(StopReason::Canceled)) => {
+                    DropReason::Done
+                }
+                (Ordering::Equal, Some(StopReason::Failed)) => DropReason::Failed,
+                (Ordering::Equal, Some(StopReason::TimedOut)) => DropReason::TimedOut,
+                _ => rest,
+            };
+
+            action.on_remove(agent, world);
+            action.on_drop(agent, world, reason);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use bevy_ecs::{error::BevyError, system::SystemId};
+
+use super::*;
+
+type Run = Box<dyn FnMut(Entity, &mut World) -> Result<bool, BevyError> + Send + Sync>;
+
+/// An action that runs a registered one-shot system with the `agent` as input.
+///
+/// The system is run using [`World::run_system_with`] when the action is [`started`](Action::on_start).
+/// If the system cannot be run, the action [`fails`](ActionStatus::Failed).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Health(u32);
+/// #
+/// fn heal(In(agent): In<Entity>, mut health_q: Query<&mut Health>) {
+///     if let Ok(mut health) = health_q.get_mut(agent) {
+///         health.0 += 10;
+///     }
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     let heal = commands.register_system(heal);
+///     let agent = commands.spawn(SequentialActions).id();
+///     commands.actions(agent).add(RunSystem::new(heal));
+/// }
+/// ```
+pub struct RunSystem {
+    run: Run,
+    status: ActionStatus,
+}
+
+impl RunSystem {
+    /// Creates a new action that runs `system` once and then finishes.
+    pub fn new(system: SystemId<In<Entity>>) -> Self {
+        Self::from_run(Box::new(move |agent, world| {
+            world.run_system_with(system, agent)?;
+            Ok(true)
+        }))
+    }
+
+    /// Creates a new action that runs `system` once with an additional `input` and then finishes.
+    ///
+    /// The `input` is cloned for each run, so the action runs the system every time it is started,
+    /// such as when it is repeated.
+    pub fn with_input<I: Clone + Send + Sync + 'static>(
+        system: SystemId<In<(Entity, I)>>,
+        input: I,
+    ) -> Self {
+        Self::from_run(Box::new(move |agent, world| {
+            world.run_system_with(system, (agent, input.clone()))?;
+            Ok(true)
+        }))
+    }
+
+    /// Creates a new action that runs `system` once every frame until it returns `true`.
+    ///
+    /// The system is run again each frame by [`stepping`](Action::on_step) within this action,
+    /// so it behaves the same whether it is in the queue or nested inside another action.
+    pub fn until(system: SystemId<In<Entity>, bool>) -> Self {
+        Self::from_run(Box::new(move |agent, world| {
+            Ok(world.run_system_with(system, agent)?)
+        }))
+    }
+
+    fn from_run(run: Run) -> Self {
+        Self {
+            run,
+            status: ActionStatus::Running,
+        }
+    }
+
+    fn run_system(&mut self, agent: Entity, world: &mut World) {
+        self.status = match (self.run)(agent, world) {
+            Ok(true) => ActionStatus::Succeeded,
+            Ok(false) => ActionStatus::Running,
+            Err(err) => {
+                warn!("Cannot run system for agent {agent}: {err}");
+                ActionStatus::Failed
+            }
+        };
+    }
+}
+
+impl Action for RunSystem {
+    fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+        self.status.is_finished()
+    }
+
+    fn poll(&self, _agent: Entity, _world: &World) -> ActionStatus {
+        match self.status {
+            // Not finished, so run again in on_step.
+            ActionStatus::Running => ActionStatus::Step,
+            status => status,
+        }
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        self.run_system(agent, world);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.run_system(agent, world);
+        self.status == ActionStatus::Succeeded
+    }
+
+    fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
+}
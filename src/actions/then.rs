@@ -0,0 +1,300 @@
+use std::{any::Any, marker::PhantomData};
+
+use super::*;
+
+type Output = Box<dyn Any + Send>;
+type Continuation = Box<dyn FnOnce(Output) -> Option<Stage> + Send + Sync>;
+
+/// An action with a chain of actions, where each action is created from the output of the previous one.
+///
+/// Created using [`then`](ActionWithOutput::then), and the chain continues with [`then`](Self::then).
+/// Actions without an output can be created using [`then_action`](Self::then_action),
+/// in which case the output is `()`.
+/// Only the current action in the chain exists at any time, as the next action is first
+/// created when the current action has [`finished`](StopReason::Finished) with an output.
+/// If the current action finishes without an output, or is stopped for any other reason,
+/// the rest of the chain is never created.
+///
+/// The chain advances by [`stepping`](Action::on_step) to the next action within itself,
+/// so it behaves the same whether it is in the queue or nested inside another action.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # struct FindTarget(Option<Entity>);
+/// # impl Action for FindTarget {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// # struct MoveTo(Entity);
+/// # impl Action for MoveTo {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// # struct Attack(Entity);
+/// # impl Action for Attack {
+/// #     fn is_finished(&self, _a: Entity, _w: &World) -> bool { true }
+/// #     fn on_start(&mut self, _a: Entity, _w: &mut World) -> bool { true }
+/// #     fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
+/// # }
+/// # impl ActionWithOutput for Attack {
+/// #     type Output = ();
+/// #     fn take_output(&mut self, _a: Entity, _w: &mut World) -> Option<()> { Some(()) }
+/// # }
+/// #
+/// impl ActionWithOutput for FindTarget {
+///     type Output = Entity;
+///
+///     fn take_output(&mut self, _agent: Entity, _world: &mut World) -> Option<Entity> {
+///         self.0.take()
+///     }
+/// }
+///
+/// impl ActionWithOutput for MoveTo {
+///     type Output = Entity;
+///
+///     fn take_output(&mut self, _agent: Entity, _world: &mut World) -> Option<Entity> {
+///         Some(self.0)
+///     }
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     let agent = commands.spawn(SequentialActions).id();
+///     commands.actions(agent).add(
+///         FindTarget(None)
+///             .then(|target| MoveTo(target))
+///             .then(|target| Attack(target)),
+///     );
+/// }
+/// ```
+pub struct Then<Out> {
+    stage: Stage,
+    next: VecDeque<Continuation>,
+    finished: bool,
+    _marker: PhantomData<fn() -> Out>,
+}
+
+impl Then<()> {
+    pub(crate) fn new_action<A, B, F>(action: A, f: F) -> Self
+    where
+        A: ActionWithOutput,
+        B: IntoBoxedAction,
+        F: FnOnce(A::Output) -> B + Send + Sync + 'static,
+    {
+        Self {
+            stage: Stage::new(action),
+            next: VecDeque::from([Stage::action_continuation(f)]),
+            finished: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Out: Send + 'static> Then<Out> {
+    pub(crate) fn new<A, B, F>(action: A, f: F) -> Self
+    where
+        A: ActionWithOutput,
+        B: ActionWithOutput<Output = Out>,
+        F: FnOnce(A::Output) -> B + Send + Sync + 'static,
+    {
+        Self {
+            stage: Stage::new(action),
+            next: VecDeque::from([Stage::continuation(f)]),
+            finished: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates the next action from the output of the last action in the chain using `f`.
+    pub fn then<B, F>(self, f: F) -> Then<B::Output>
+    where
+        B: ActionWithOutput,
+        F: FnOnce(Out) -> B + Send + Sync + 'static,
+    {
+        self.chain(Stage::continuation(f))
+    }
+
+    /// Creates the next action from the output of the last action in the chain using `f`,
+    /// for actions without an output.
+    pub fn then_action<B, F>(self, f: F) -> Then<()>
+    where
+        B: IntoBoxedAction,
+        F: FnOnce(Out) -> B + Send + Sync + 'static,
+    {
+        self.chain(Stage::action_continuation(f))
+    }
+
+    fn chain<T>(mut self, next: Continuation) -> Then<T> {
+        self.next.push_back(next);
+        Then {
+            stage: self.stage,
+            next: self.next,
+            finished: self.finished,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of actions left to create in the chain.
+    pub fn remaining(&self) -> usize {
+        self.next.len()
+    }
+
+    /// Stops the finished action, and replaces it with the next one in the chain
+    /// until one is not immediately finished.
+    /// Returns `true` if the chain has finished.
+    fn advance(&mut self, agent: Entity, world: &mut World) -> bool {
+        loop {
+            self.stage
+                .action
+                .on_stop(Some(agent), world, StopReason::Finished);
+
+            let next = self.next.pop_front().and_then(|next| {
+                (self.stage.output)(&mut self.stage.action, agent, world).and_then(next)
+            });
+
+            let Some(mut stage) = next else {
+                // No more actions or no output, so the chain ends here.
+                self.finished = true;
+                return true;
+            };
+
+            // Replace the finished action with the next one in the chain.
+            self.stage.action.on_remove(Some(agent), world);
+            stage.action.on_add(agent, world);
+            let Stage { action, .. } = std::mem::replace(&mut self.stage, stage);
+            action.on_drop(Some(agent), world, DropReason::Done);
+
+            if !self.stage.action.on_start(agent, world) {
+                return false;
+            }
+        }
+    }
+}
+
+impl<Out: Send + 'static> Action for Then<Out> {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        if self.finished {
+            return ActionStatus::Succeeded;
+        }
+
+        match self.stage.action.poll(agent, world) {
+            // Not the last action, so step to the next one.
+            ActionStatus::Succeeded if !self.next.is_empty() => ActionStatus::Step,
+            status => status,
+        }
+    }
+
+    fn is_polled(&self) -> bool {
+        self.stage.action.is_polled()
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        if step(&mut self.stage.action, agent, world) == ActionStatus::Succeeded
+            && !self.next.is_empty()
+        {
+            self.advance(agent, world);
+        }
+    }
+
+    fn on_finish(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.stage.action.on_finish(agent, world)
+            && (self.next.is_empty() || self.advance(agent, world))
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.stage.action.on_add(agent, world);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        self.stage.action.on_start(agent, world)
+            && (self.next.is_empty() || self.advance(agent, world))
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        // The last action has already been stopped when the chain finished early.
+        if !self.finished {
+            self.stage.action.on_stop(agent, world, reason);
+        }
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.stage.action.on_remove(agent, world);
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        self.stage.action.on_drop(agent, world, reason);
+    }
+}
+
+impl<Out: Send + 'static> ActionWithOutput for Then<Out> {
+    type Output = Out;
+
+    fn take_output(&mut self, agent: Entity, world: &mut World) -> Option<Out> {
+        if !self.next.is_empty() {
+            return None;
+        }
+
+        (self.stage.output)(&mut self.stage.action, agent, world)?
+            .downcast()
+            .ok()
+            .map(|output| *output)
+    }
+}
+
+/// An action in a [`Then`] chain, together with a way of taking its output.
+struct Stage {
+    action: BoxedAction,
+    output: fn(&mut BoxedAction, Entity, &mut World) -> Option<Output>,
+}
+
+impl Stage {
+    fn new<A: ActionWithOutput>(action: A) -> Self {
+        Self {
+            action: Box::new(action),
+            output: Self::take_output::<A>,
+        }
+    }
+
+    fn take_output<A: ActionWithOutput>(
+        action: &mut BoxedAction,
+        agent: Entity,
+        world: &mut World,
+    ) -> Option<Output> {
+        let output = action.downcast_mut::<A>()?.take_output(agent, world)?;
+        Some(Box::new(output))
+    }
+
+    fn continuation<In: 'static, B, F>(f: F) -> Continuation
+    where
+        B: ActionWithOutput,
+        F: FnOnce(In) -> B + Send + Sync + 'static,
+    {
+        Box::new(move |output: Output| {
+            let input = output.downcast::<In>().ok()?;
+            Some(Self::new(f(*input)))
+        })
+    }
+
+    fn action_continuation<In: 'static, B, F>(f: F) -> Continuation
+    where
+        B: IntoBoxedAction,
+        F: FnOnce(In) -> B + Send + Sync + 'static,
+    {
+        Box::new(move |output: Output| {
+            let input = output.downcast::<In>().ok()?;
+            Some(Self {
+                action: f(*input).into_boxed_action(),
+                output: |_, _, _| Some(Box::new(())),
+            })
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+/// An action that [`times out`](ActionStatus::TimedOut) its inner action
+/// if it has not finished within a specified duration.
+///
+/// The elapsed time is measured using the [`Time`] resource.
+/// When paused, the remaining time is kept until the action is started again.
+///
+/// Once timed out, the inner action is [`stopped`](Action::on_stop)
+/// and [`dropped`](Action::on_drop) as [`timed out`](StopReason::TimedOut),
+/// and the `agent` handles it according to its [`OnFailure`] policy.
+pub struct Timeout {
+    action: BoxedAction,
+    duration: Duration,
+    remaining: Option<Duration>,
+    deadline: Option<Duration>,
+}
+
+impl Timeout {
+    /// Creates a new action that times out `action` after `duration`.
+    pub fn new(action: impl IntoBoxedAction, duration: Duration) -> Self {
+        Self {
+            action: action.into_boxed_action(),
+            duration,
+            remaining: None,
+            deadline: None,
+        }
+    }
+
+    /// Returns the duration before timing out.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl Action for Timeout {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.poll(agent, world).is_finished()
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        match self.action.poll(agent, world) {
+            status @ (ActionStatus::Running | ActionStatus::Step) => {
+                match (self.deadline, elapsed(world)) {
+                    (Some(deadline), Some(elapsed)) if elapsed >= deadline => {
+                        ActionStatus::TimedOut
+                    }
+                    _ => status,
+                }
+            }
+            status => status,
+        }
+    }
+
+    fn on_step(&mut self, agent: Entity, world: &mut World) {
+        self.action.on_step(agent, world);
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        self.action.on_add(agent, world);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        // Take remaining time (if paused), or use full duration.
+        let remaining = self.remaining.take().unwrap_or(self.duration);
+
+        match elapsed(world) {
+            Some(elapsed) => self.deadline = Some(elapsed + remaining),
+            None => warn!(
+                "Cannot time out action {:?} for agent {agent} due to missing resource {}.",
+                self.action,
+                std::any::type_name::<Time>()
+            ),
+        }
+
+        self.action.on_start(agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        // Store remaining time when paused.
+        if let (StopReason::Paused, Some(deadline), Some(elapsed)) =
+            (reason, self.deadline, elapsed(world))
+        {
+            self.remaining = Some(deadline.saturating_sub(elapsed));
+        }
+
+        self.deadline = None;
+        self.action.on_stop(agent, world, reason);
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        self.action.on_remove(agent, world);
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        self.action.on_drop(agent, world, reason);
+    }
+}
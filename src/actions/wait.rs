@@ -0,0 +1,115 @@
+use super::*;
+
+/// An action that waits until a condition is `true`.
+///
+/// The condition is evaluated when the action is [`started`](Action::on_start),
+/// and then every time the action is [`polled`](Action::poll).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Door { open: bool }
+/// #
+/// fn setup(mut commands: Commands) {
+///     let door = commands.spawn(Door { open: false }).id();
+///     let agent = commands.spawn(SequentialActions).id();
+///     commands.actions(agent).add(
+///         WaitUntil::new(move |_agent, world: &World| world.get::<Door>(door).unwrap().open)
+///             .on_start(|agent, _world: &mut World| println!("Agent {agent} is waiting for the door.")),
+///     );
+/// }
+/// ```
+pub struct WaitUntil {
+    cond: Condition,
+    on_start: Option<OnStart>,
+    on_stop: Option<OnStop>,
+}
+
+impl WaitUntil {
+    /// Creates a new action that waits until `cond` returns `true`.
+    pub fn new(cond: impl Fn(Entity, &World) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            cond: Box::new(cond),
+            on_start: None,
+            on_stop: None,
+        }
+    }
+
+    /// Calls `f` when the action is [`started`](Action::on_start).
+    pub fn on_start(mut self, f: impl FnMut(Entity, &mut World) + Send + Sync + 'static) -> Self {
+        self.on_start = Some(Box::new(f));
+        self
+    }
+
+    /// Calls `f` when the action is [`stopped`](Action::on_stop).
+    pub fn on_stop(
+        mut self,
+        f: impl FnMut(Option<Entity>, &mut World, StopReason) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_stop = Some(Box::new(f));
+        self
+    }
+}
+
+impl Action for WaitUntil {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        (self.cond)(agent, world)
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        if let Some(on_start) = &mut self.on_start {
+            on_start(agent, world);
+        }
+
+        self.is_finished(agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        if let Some(on_stop) = &mut self.on_stop {
+            on_stop(agent, world, reason);
+        }
+    }
+}
+
+/// An action that waits while a condition is `true`.
+///
+/// The inverse of [`WaitUntil`].
+pub struct WaitWhile(WaitUntil);
+
+impl WaitWhile {
+    /// Creates a new action that waits while `cond` returns `true`.
+    pub fn new(cond: impl Fn(Entity, &World) -> bool + Send + Sync + 'static) -> Self {
+        Self(WaitUntil::new(move |agent, world| !cond(agent, world)))
+    }
+
+    /// Calls `f` when the action is [`started`](Action::on_start).
+    pub fn on_start(self, f: impl FnMut(Entity, &mut World) + Send + Sync + 'static) -> Self {
+        Self(self.0.on_start(f))
+    }
+
+    /// Calls `f` when the action is [`stopped`](Action::on_stop).
+    pub fn on_stop(
+        self,
+        f: impl FnMut(Option<Entity>, &mut World, StopReason) + Send + Sync + 'static,
+    ) -> Self {
+        Self(self.0.on_stop(f))
+    }
+}
+
+impl Action for WaitWhile {
+    fn is_finished(&self, agent: Entity, world: &World) -> bool {
+        self.0.is_finished(agent, world)
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        Action::on_start(&mut self.0, agent, world)
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        Action::on_stop(&mut self.0, agent, world, reason);
+    }
+}
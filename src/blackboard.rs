@@ -0,0 +1,226 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    hash::{BuildHasherDefault, DefaultHasher},
+    marker::PhantomData,
+};
+
+use super::*;
+
+/// A typed key for a value in a [`Blackboard`].
+///
+/// Keys are identified by both their name and value type `T`,
+/// so keys with the same name but different types do not collide.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// const TARGET: BlackboardKey<Entity> = BlackboardKey::new("target");
+///
+/// struct MoveToTarget;
+///
+/// impl Action for MoveToTarget {
+///     fn is_finished(&self, agent: Entity, world: &World) -> bool {
+///         TARGET.get(agent, world).is_none()
+///     }
+///
+///     fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+///         // Read the target written by a previous action.
+///         let Some(&target) = TARGET.get(agent, world) else {
+///             return true;
+///         };
+/// #       let _ = target;
+///         // ...
+///         self.is_finished(agent, world)
+///     }
+///
+///     fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {}
+/// }
+/// ```
+pub struct BlackboardKey<T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> BlackboardKey<T> {
+    /// Creates a new key with specified `name`.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the name of the key.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T: Send + Sync + 'static> BlackboardKey<T> {
+    /// Returns the value for this key in the [`Blackboard`] of `agent`.
+    pub fn get(self, agent: Entity, world: &World) -> Option<&T> {
+        world.get::<Blackboard>(agent)?.get(self)
+    }
+
+    /// Inserts a `value` for this key in the [`Blackboard`] of `agent`,
+    /// and returns the previous value if any.
+    ///
+    /// Does nothing if `agent` does not exist or is missing the component.
+    pub fn set(self, agent: Entity, world: &mut World, value: T) -> Option<T> {
+        let Some(mut blackboard) = world.get_mut::<Blackboard>(agent) else {
+            warn!(
+                "Cannot set blackboard value for key {self:?} for agent {agent} \
+                due to missing component {}.",
+                std::any::type_name::<Blackboard>()
+            );
+            return None;
+        };
+
+        blackboard.insert(self, value)
+    }
+
+    /// Removes and returns the value for this key in the [`Blackboard`] of `agent`.
+    pub fn take(self, agent: Entity, world: &mut World) -> Option<T> {
+        world.get_mut::<Blackboard>(agent)?.remove(self)
+    }
+}
+
+impl<T> Clone for BlackboardKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BlackboardKey<T> {}
+
+impl<T> PartialEq for BlackboardKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<T> Eq for BlackboardKey<T> {}
+
+impl<T> std::hash::Hash for BlackboardKey<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl<T> Debug for BlackboardKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, std::any::type_name::<T>())
+    }
+}
+
+/// Typed storage for passing data between the actions of an `agent`.
+///
+/// Values are accessed using a [`BlackboardKey`].
+/// A common pattern is for an action to write its output in [`on_stop`](Action::on_stop)
+/// or [`on_drop`](Action::on_drop), which the following actions then read in [`on_start`](Action::on_start).
+///
+/// Values are kept when the action queue is cleared, unless [`reset_on_clear`](Self::reset_on_clear) is set.
+#[derive(Default, Component)]
+pub struct Blackboard {
+    values: HashMap<(&'static str, TypeId), Box<dyn Any + Send + Sync>, BlackboardHasher>,
+    reset_on_clear: bool,
+}
+
+/// A fixed hasher so that a [`Blackboard`] can be created in a `const` context.
+type BlackboardHasher = BuildHasherDefault<DefaultHasher>;
+
+impl Blackboard {
+    /// Creates a new empty blackboard.
+    pub const fn new() -> Self {
+        Self {
+            values: HashMap::with_hasher(BuildHasherDefault::new()),
+            reset_on_clear: false,
+        }
+    }
+
+    /// Sets whether to remove all values when the action queue is [`cleared`](ManageActions::clear)
+    /// or [`replaced`](AddOrder::Replace).
+    pub fn reset_on_clear(mut self, reset: bool) -> Self {
+        self.reset_on_clear = reset;
+        self
+    }
+
+    /// Returns `true` if all values are removed when the action queue is cleared.
+    pub fn resets_on_clear(&self) -> bool {
+        self.reset_on_clear
+    }
+
+    /// Returns the value for `key`.
+    pub fn get<T: Send + Sync + 'static>(&self, key: BlackboardKey<T>) -> Option<&T> {
+        self.values
+            .get(&(key.name, TypeId::of::<T>()))
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    pub fn get_mut<T: Send + Sync + 'static>(&mut self, key: BlackboardKey<T>) -> Option<&mut T> {
+        self.values
+            .get_mut(&(key.name, TypeId::of::<T>()))
+            .and_then(|value| value.downcast_mut())
+    }
+
+    /// Inserts a `value` for `key`, and returns the previous value if any.
+    pub fn insert<T: Send + Sync + 'static>(
+        &mut self,
+        key: BlackboardKey<T>,
+        value: T,
+    ) -> Option<T> {
+        self.values
+            .insert((key.name, TypeId::of::<T>()), Box::new(value))
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+
+    /// Removes and returns the value for `key`.
+    pub fn remove<T: Send + Sync + 'static>(&mut self, key: BlackboardKey<T>) -> Option<T> {
+        self.values
+            .remove(&(key.name, TypeId::of::<T>()))
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+
+    /// Returns `true` if there is a value for `key`.
+    pub fn contains<T: Send + Sync + 'static>(&self, key: BlackboardKey<T>) -> bool {
+        self.values.contains_key(&(key.name, TypeId::of::<T>()))
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl Debug for Blackboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Blackboard")
+            .field(
+                "keys",
+                &self
+                    .values
+                    .keys()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("reset_on_clear", &self.reset_on_clear)
+            .finish()
+    }
+}
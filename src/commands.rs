@@ -58,6 +58,32 @@ impl ManageActions for AgentCommands<'_, '_, '_> {
         self
     }
 
+    fn add_with_id(&mut self, action: impl IntoBoxedAction) -> ActionId {
+        let agent = self.agent;
+        let config = self.config;
+        let action = action.into_boxed_action();
+        let id = ActionId::new();
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::add_action_with_id(agent, config, id, action, world);
+        });
+
+        id
+    }
+
+    fn interrupt(&mut self, actions: impl IntoBoxedActions) -> &mut Self {
+        let agent = self.agent;
+        let actions = actions.into_boxed_actions();
+
+        if actions.len() > 0 {
+            self.commands.queue(move |world: &mut World| {
+                SequentialActionsPlugin::interrupt_actions(agent, actions, world);
+            });
+        }
+
+        self
+    }
+
     fn execute(&mut self) -> &mut Self {
         let agent = self.agent;
 
@@ -79,6 +105,16 @@ impl ManageActions for AgentCommands<'_, '_, '_> {
         self
     }
 
+    fn finish(&mut self) -> &mut Self {
+        let agent = self.agent;
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::finish_action(agent, world);
+        });
+
+        self
+    }
+
     fn cancel(&mut self) -> &mut Self {
         let agent = self.agent;
 
@@ -89,6 +125,16 @@ impl ManageActions for AgentCommands<'_, '_, '_> {
         self
     }
 
+    fn cancel_if_current(&mut self, id: ActionId) -> &mut Self {
+        let agent = self.agent;
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::cancel_action_if_current(agent, id, world);
+        });
+
+        self
+    }
+
     fn pause(&mut self) -> &mut Self {
         let agent = self.agent;
 
@@ -99,6 +145,26 @@ impl ManageActions for AgentCommands<'_, '_, '_> {
         self
     }
 
+    fn move_to(&mut self, from: usize, to: usize) -> &mut Self {
+        let agent = self.agent;
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::move_action(agent, from, to, world);
+        });
+
+        self
+    }
+
+    fn swap(&mut self, a: usize, b: usize) -> &mut Self {
+        let agent = self.agent;
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::swap_actions(agent, a, b, world);
+        });
+
+        self
+    }
+
     fn skip(&mut self, n: usize) -> &mut Self {
         let agent = self.agent;
 
@@ -109,6 +175,26 @@ impl ManageActions for AgentCommands<'_, '_, '_> {
         self
     }
 
+    fn remove(&mut self, id: ActionId) -> &mut Self {
+        let agent = self.agent;
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::remove_action(agent, id, world);
+        });
+
+        self
+    }
+
+    fn retain(&mut self, f: impl FnMut(&dyn Action) -> bool + Send + 'static) -> &mut Self {
+        let agent = self.agent;
+
+        self.commands.queue(move |world: &mut World| {
+            SequentialActionsPlugin::retain_actions(agent, f, world);
+        });
+
+        self
+    }
+
     fn clear(&mut self) -> &mut Self {
         let agent = self.agent;
 
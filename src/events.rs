@@ -0,0 +1,63 @@
+use super::*;
+
+/// [`EntityEvent`] triggered on an `agent` when an [`action`](Action) has been [`started`](Action::on_start).
+///
+/// This is not triggered if `agent` was despawned while starting the action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EntityEvent)]
+pub struct ActionStarted {
+    /// The `agent` that started the action.
+    #[event_target]
+    pub agent: Entity,
+    /// The [`type name`](Action::type_name) of the action.
+    pub action: &'static str,
+}
+
+/// [`EntityEvent`] triggered on an `agent` when an [`action`](Action) has been [`stopped`](Action::on_stop).
+///
+/// This is not triggered if `agent` no longer exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EntityEvent)]
+pub struct ActionStopped {
+    /// The `agent` that stopped the action.
+    #[event_target]
+    pub agent: Entity,
+    /// The [`type name`](Action::type_name) of the action.
+    pub action: &'static str,
+    /// The reason why the action was stopped.
+    pub reason: StopReason,
+}
+
+/// [`EntityEvent`] triggered on an `agent` when an [`action`](Action) has been [`dropped`](Action::on_drop).
+///
+/// This is not triggered if `agent` no longer exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EntityEvent)]
+pub struct ActionDropped {
+    /// The `agent` that dropped the action.
+    #[event_target]
+    pub agent: Entity,
+    /// The [`type name`](Action::type_name) of the action.
+    pub action: &'static str,
+    /// The reason why the action was dropped.
+    pub reason: DropReason,
+}
+
+/// [`EntityEvent`] that can be triggered on an `agent` to [`finish`](ManageActions::finish)
+/// its current [`action`](Action).
+///
+/// This is mainly used for completing actions that are not [`polled`](Action::is_polled).
+/// The current action is stopped as [`finished`](StopReason::Finished),
+/// and the next action in the queue is started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EntityEvent)]
+pub struct FinishAction {
+    /// The `agent` with the action to finish.
+    #[event_target]
+    pub agent: Entity,
+}
+
+/// [`EntityEvent`] triggered on an `agent` when it tries to start the next [`action`](Action),
+/// but the action queue is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EntityEvent)]
+pub struct QueueEmptied {
+    /// The `agent` with the empty action queue.
+    #[event_target]
+    pub agent: Entity,
+}
@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::*;
+
+/// A unique identifier for an [`action`](Action) added with [`add_with_id`](ManageActions::add_with_id).
+///
+/// Identifiers are never reused, and can be created ahead of time
+/// since they do not depend on the [`World`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ActionId(u64);
+
+impl ActionId {
+    /// Returns a new unique identifier.
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for ActionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The status of an [`action`](Action) with a specific [`ActionId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionIdStatus {
+    /// The action is the current action.
+    Current,
+    /// The action is in the queue at the given index.
+    Queued(usize),
+    /// The action was not found. It has either been dropped, or never added to this `agent`.
+    Unknown,
+}
+
+impl CurrentAction {
+    /// Returns the [`ActionId`] of the current action if it has one.
+    pub fn id(&self) -> Option<ActionId> {
+        self.0.as_ref().and(self.1)
+    }
+
+    /// Sets the current action with its `id`.
+    pub(crate) fn set(&mut self, action: BoxedAction, id: Option<ActionId>) {
+        self.0 = Some(action);
+        self.1 = id;
+    }
+
+    /// Takes the current action with its id.
+    pub(crate) fn take_with_id(&mut self) -> Option<(BoxedAction, Option<ActionId>)> {
+        let id = self.1.take();
+        self.0.take().map(|action| (action, id))
+    }
+}
+
+impl ActionQueue {
+    /// Returns the index of the queued action with specified `id`.
+    pub fn position(&self, id: ActionId) -> Option<usize> {
+        self.1
+            .iter()
+            .take(self.0.len())
+            .position(|other| *other == Some(id))
+    }
+
+    /// Inserts `action` with its `id` at `index`.
+    pub(crate) fn insert_with_id(
+        &mut self,
+        index: usize,
+        action: BoxedAction,
+        id: Option<ActionId>,
+    ) {
+        self.sync_ids();
+        self.0.insert(index, action);
+        self.1.insert(index, id);
+    }
+
+    /// Removes the action at `index` with its id.
+    pub(crate) fn remove_with_id(
+        &mut self,
+        index: usize,
+    ) -> Option<(BoxedAction, Option<ActionId>)> {
+        self.sync_ids();
+        let action = self.0.remove(index)?;
+        Some((action, self.1.remove(index).flatten()))
+    }
+
+    /// Swaps the actions at index `a` and `b` with their ids.
+    pub(crate) fn swap_with_ids(&mut self, a: usize, b: usize) {
+        self.sync_ids();
+        self.0.swap(a, b);
+        self.1.swap(a, b);
+    }
+
+    /// Keeps one id per queued action in case the queue was modified directly.
+    fn sync_ids(&mut self) {
+        self.1.resize(self.0.len(), None);
+    }
+}
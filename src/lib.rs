@@ -34,7 +34,7 @@ use bevy_sequential_actions::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, SequentialActionsPlugin))
+        .add_plugins((DefaultPlugins, SequentialActionsPlugin::default()))
         .run();
 }
 ```
@@ -118,6 +118,27 @@ fn wait_system(mut wait_timer_q: Query<&mut WaitTimer>, time: Res<Time>) {
 }
 ```
 
+Simple actions can instead use the [`Action`](macro@Action) derive macro,
+which is enabled by the `derive` feature.
+
+```rust,no_run
+# use bevy_ecs::prelude::*;
+# use bevy_sequential_actions::*;
+#
+# #[derive(Component)]
+# struct Open;
+#
+#[derive(Action)]
+#[action(finished = "OpenDoor::is_open", name = "OpenDoor")]
+struct OpenDoor(Entity);
+
+impl OpenDoor {
+    fn is_open(&self, agent: Entity, world: &World) -> bool {
+        world.entity(self.0).contains::<Open>()
+    }
+}
+```
+
 #### Managing Actions
 
 Actions can be added to any [`Entity`] with the [`SequentialActions`] marker component.
@@ -225,7 +246,7 @@ There are a few things you should keep in mind:
   #   fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
   fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
       match reason {
-          DropReason::Done => {
+          DropReason::Done | DropReason::Failed | DropReason::TimedOut => {
               // ...
           }
           DropReason::Skipped => {
@@ -236,30 +257,52 @@ There are a few things you should keep in mind:
               // All new actions added to the queue here
               // will also be cleared
           }
+          DropReason::Replaced => {
+              // All new actions added to the queue here
+              // will also be replaced
+          }
       }
   }
   # }
   ```
 */
 
-use std::{collections::VecDeque, fmt::Debug};
+use std::{any::TypeId, collections::VecDeque, fmt::Debug};
 
 use bevy_app::prelude::*;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{lifecycle::HookContext, prelude::*, query::QueryFilter, world::DeferredWorld};
 use bevy_log::{debug, warn};
 
+mod actions;
+mod blackboard;
 mod commands;
+mod events;
+mod id;
 mod macros;
 mod plugin;
 mod traits;
+mod view;
 mod world;
 
+pub use actions::*;
+pub use blackboard::*;
 pub use commands::*;
+pub use events::*;
+pub use id::*;
 pub use plugin::*;
 pub use traits::*;
+pub use view::*;
 pub use world::*;
 
+#[cfg(feature = "derive")]
+pub use bevy_sequential_actions_derive::Action;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use bevy_ecs::{entity::Entity, world::World};
+}
+
 /// A boxed [`Action`].
 pub type BoxedAction = Box<dyn Action>;
 
@@ -267,13 +310,13 @@ pub type BoxedAction = Box<dyn Action>;
 ///
 /// This component is all that is needed for spawning an agent that you can add actions to.
 /// Required components will bring in the necessary components,
-/// namely [CurrentAction] and [ActionQueue].
+/// namely [CurrentAction], [ActionQueue], [Blackboard] and [Idle].
 ///
 /// If you do not care for the marker,
 /// or perhaps don't want to use required components,
 /// there is still the [ActionsBundle] for spawning an agent as before.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component)]
-#[require(CurrentAction, ActionQueue)]
+#[require(CurrentAction, ActionQueue, Blackboard, Idle)]
 pub struct SequentialActions;
 
 /// The component bundle that all entities with actions must have.
@@ -281,6 +324,8 @@ pub struct SequentialActions;
 pub struct ActionsBundle {
     current: CurrentAction,
     queue: ActionQueue,
+    blackboard: Blackboard,
+    idle: Idle,
 }
 
 impl ActionsBundle {
@@ -288,23 +333,47 @@ impl ActionsBundle {
     /// that all entities with actions must have.
     pub const fn new() -> Self {
         Self {
-            current: CurrentAction(None),
-            queue: ActionQueue(VecDeque::new()),
+            current: CurrentAction(None, None),
+            queue: ActionQueue(VecDeque::new(), VecDeque::new()),
+            blackboard: Blackboard::new(),
+            idle: Idle,
         }
     }
 
     /// Creates a new [`Bundle`] with specified `capacity` for the action queue.
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            current: CurrentAction(None),
-            queue: ActionQueue(VecDeque::with_capacity(capacity)),
+            current: CurrentAction(None, None),
+            queue: ActionQueue(
+                VecDeque::with_capacity(capacity),
+                VecDeque::with_capacity(capacity),
+            ),
+            blackboard: Blackboard::new(),
+            idle: Idle,
         }
     }
 }
 
+/// Marker component for agents without a [current action](CurrentAction).
+///
+/// The marker is maintained by [`SequentialActionsPlugin`] as actions are started and stopped,
+/// and is useful for filtering agents in your own systems, such as with `With<Idle>`.
+/// It can also be used as a run condition with [`any_with_component`].
+///
+/// Note that the marker is not updated when modifying [`CurrentAction`] directly.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component)]
+#[component(storage = "SparseSet")]
+pub struct Idle;
+
 /// The current action for an `agent`.
+///
+/// Only agents without the [`Idle`] marker have their current action polled.
+/// Setting the current action directly, such as through [`DerefMut`](std::ops::DerefMut),
+/// does not remove the marker, so the action is never polled.
+/// Use [`ManageActions`] instead, which keeps the marker in sync.
+/// This also applies to the [`ActionId`] of the current action.
 #[derive(Debug, Default, Component, Deref, DerefMut)]
-pub struct CurrentAction(Option<BoxedAction>);
+pub struct CurrentAction(#[deref] Option<BoxedAction>, Option<ActionId>);
 
 impl CurrentAction {
     /// The [`on_remove`](bevy_ecs::lifecycle::ComponentHooks::on_remove) component lifecycle hook
@@ -341,8 +410,13 @@ impl CurrentAction {
 }
 
 /// The action queue for an `agent`.
+///
+/// Each queued action may have an [`ActionId`], which is stored next to the queue.
+/// Modifying the queue directly, such as through [`DerefMut`](std::ops::DerefMut),
+/// does not move the ids along with the actions.
+/// Use [`ManageActions`] instead, which keeps the ids in sync.
 #[derive(Debug, Default, Component, Deref, DerefMut)]
-pub struct ActionQueue(VecDeque<BoxedAction>);
+pub struct ActionQueue(#[deref] VecDeque<BoxedAction>, VecDeque<Option<ActionId>>);
 
 impl ActionQueue {
     /// The [`on_remove`](bevy_ecs::lifecycle::ComponentHooks::on_remove) component lifecycle hook
@@ -382,6 +456,22 @@ impl ActionQueue {
     }
 }
 
+impl ActionQueue {
+    /// Returns the index in the queue for inserting actions with specified `order`.
+    fn order_index(&self, order: AddOrder) -> usize {
+        match order {
+            AddOrder::Back | AddOrder::Replace => self.0.len(),
+            AddOrder::Front => 0,
+            AddOrder::Index(index) => index.min(self.0.len()),
+            AddOrder::AfterType(type_id) => self
+                .0
+                .iter()
+                .position(|action| action.as_any().type_id() == type_id)
+                .map_or(self.0.len(), |index| index + 1),
+        }
+    }
+}
+
 /// Configuration for actions to be added.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AddConfig {
@@ -412,6 +502,80 @@ pub enum AddOrder {
     Back,
     /// An action is added to the front of the queue.
     Front,
+    /// An action is inserted at the given index in the queue.
+    /// An index beyond the queue adds the action to the back.
+    Index(usize),
+    /// An action is inserted right after the first action in the queue with the given [`TypeId`].
+    /// If no such action exists, the action is added to the back.
+    ///
+    /// See [`AddOrder::after_type`] for a convenient constructor.
+    AfterType(TypeId),
+    /// The action queue is cleared before an action is added.
+    ///
+    /// Current action is [`stopped`](Action::on_stop) as [`canceled`](StopReason::Canceled),
+    /// and all actions are [`dropped`](Action::on_drop) as [`replaced`](DropReason::Replaced).
+    Replace,
+}
+
+impl AddOrder {
+    /// Returns an [`AddOrder::AfterType`] for action type `T`.
+    pub fn after_type<T: Action>() -> Self {
+        Self::AfterType(TypeId::of::<T>())
+    }
+}
+
+/// The policy for an `agent` when an [`Action`] has [`failed`](ActionStatus::Failed)
+/// or [`timed out`](ActionStatus::TimedOut).
+///
+/// Agents without this component use the default policy [`OnFailure::Continue`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum OnFailure {
+    /// The failed action is dropped, and the next action in the queue is started.
+    #[default]
+    Continue,
+    /// The failed action is dropped, and the rest of the action queue is cleared.
+    Clear,
+    /// The failed action is put back into the front of the queue,
+    /// and will start again just like a paused action.
+    ///
+    /// Since an action that keeps failing is retried forever,
+    /// consider using [`on_stop`](Action::on_stop) for limiting the attempts.
+    Retry,
+}
+
+/// The outcome of an [`Action`] when [`polled`](Action::poll).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionStatus {
+    /// The action is still running.
+    Running,
+    /// The action is still running, but needs to [`step`](Action::on_step)
+    /// with mutable access to the [`World`].
+    Step,
+    /// The action has finished successfully.
+    Succeeded,
+    /// The action has finished, but failed.
+    Failed,
+    /// The action did not finish in time.
+    TimedOut,
+}
+
+impl ActionStatus {
+    /// Returns `true` if the action has finished,
+    /// meaning it is neither [`running`](Self::Running) nor needs to [`step`](Self::Step).
+    pub const fn is_finished(self) -> bool {
+        self.stop_reason().is_some()
+    }
+
+    /// Returns the [`StopReason`] for this status,
+    /// or `None` if the action is still [`running`](Self::Running) or needs to [`step`](Self::Step).
+    pub const fn stop_reason(self) -> Option<StopReason> {
+        match self {
+            Self::Running | Self::Step => None,
+            Self::Succeeded => Some(StopReason::Finished),
+            Self::Failed => Some(StopReason::Failed),
+            Self::TimedOut => Some(StopReason::TimedOut),
+        }
+    }
 }
 
 /// The reason why an [`Action`] was stopped.
@@ -423,6 +587,10 @@ pub enum StopReason {
     Canceled,
     /// The action was paused.
     Paused,
+    /// The action has finished, but failed.
+    Failed,
+    /// The action did not finish in time. See [`Timeout`].
+    TimedOut,
 }
 
 /// The reason why an [`Action`] was dropped.
@@ -431,10 +599,17 @@ pub enum DropReason {
     /// The action is considered done as it was either finished or canceled
     /// without being skipped or cleared from the action queue.
     Done,
+    /// The action has finished, but failed.
+    Failed,
+    /// The action did not finish in time. See [`Timeout`].
+    TimedOut,
     /// The action was skipped. This happens either deliberately,
     /// or because an action was added to an `agent` that does not exist or is missing a component.
     Skipped,
     /// The action queue was cleared. This happens either deliberately,
     /// or because an `agent` was despawned.
     Cleared,
+    /// The action queue was cleared due to new actions being added
+    /// with [`AddOrder::Replace`].
+    Replaced,
 }
@@ -1,30 +1,159 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy_utils::Parallel;
+
 use super::*;
 
+/// The query data used for polling the current action of agents.
+type AgentData = (Entity, &'static CurrentAction);
+
 /// The [`Plugin`] for this library that you can add to your [`App`].
 ///
-/// This plugin adds the [`check_actions`](Self::check_actions) system to the [`Last`] schedule
+/// By default, this plugin adds the [`check_actions`](Self::check_actions) system to the [`Last`] schedule
 /// for action queue advancement, and also two [`hooks`](bevy_ecs::lifecycle::ComponentHooks)
 /// for cleaning up actions from despawned agents.
+/// An observer for the [`FinishAction`] event is added as well.
+///
+/// The schedule, the query filter `F` for agents and the [`CleanupMode`] can all be configured.
+/// The system is added to the [`SequentialActionsSet`] for ordering.
+/// Multiple plugins can be added as long as their filters are disjoint,
+/// such as for advancing the action queue of different agents in different schedules.
 ///
 /// Finally, it also contains various static methods for managing the action queue.
-pub struct SequentialActionsPlugin;
+/// These methods trigger events such as [`ActionStarted`] and [`ActionStopped`] on agents,
+/// which can be observed without touching any action code.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_app::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Player;
+/// #
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         SequentialActionsPlugin::new().with_filter::<Without<Player>>(),
+///         SequentialActionsPlugin::new()
+///             .in_schedule(FixedUpdate)
+///             .with_filter::<With<Player>>()
+///             .with_cleanup(CleanupMode::Observers),
+///     ))
+///     .run();
+/// # }
+/// ```
+pub struct SequentialActionsPlugin<F: QueryFilter = ()> {
+    schedule: InternedScheduleLabel,
+    cleanup: CleanupMode,
+    filter: PhantomData<fn() -> F>,
+}
+
+/// The [`SystemSet`] containing the system added by [`SequentialActionsPlugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct SequentialActionsSet;
+
+/// How actions are cleaned up when an `agent` is despawned.
+///
+/// Used by [`SequentialActionsPlugin`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupMode {
+    /// Cleans up actions using component [`hooks`](bevy_ecs::lifecycle::ComponentHooks).
+    ///
+    /// Hooks apply to all agents regardless of the query filter,
+    /// and are only registered once.
+    /// Since hooks are global, they also override the cleanup mode of every other plugin,
+    /// so use either [`Observers`](Self::Observers) or [`None`](Self::None) for all plugins
+    /// when adding more than one.
+    #[default]
+    Hooks,
+    /// Cleans up actions using [`observers`](Observer) that respect the query filter.
+    Observers,
+    /// Actions are not cleaned up.
+    None,
+}
+
+impl SequentialActionsPlugin<()> {
+    /// Creates a new plugin with the default configuration.
+    pub fn new() -> Self {
+        Self {
+            schedule: Last.intern(),
+            cleanup: CleanupMode::default(),
+            filter: PhantomData,
+        }
+    }
+}
+
+impl Default for SequentialActionsPlugin<()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: QueryFilter> SequentialActionsPlugin<F> {
+    /// Sets the `schedule` for advancing the action queue.
+    ///
+    /// Default is [`Last`].
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Sets the query filter `G` for agents.
+    ///
+    /// Default is the unit type `()` for no filtering.
+    pub fn with_filter<G: QueryFilter>(self) -> SequentialActionsPlugin<G> {
+        SequentialActionsPlugin {
+            schedule: self.schedule,
+            cleanup: self.cleanup,
+            filter: PhantomData,
+        }
+    }
+
+    /// Sets the [`CleanupMode`] for despawned agents.
+    ///
+    /// Default is [`CleanupMode::Hooks`].
+    pub fn with_cleanup(mut self, cleanup: CleanupMode) -> Self {
+        self.cleanup = cleanup;
+        self
+    }
+}
 
-impl Plugin for SequentialActionsPlugin {
+impl<F: QueryFilter + 'static> Plugin for SequentialActionsPlugin<F> {
     fn build(&self, app: &mut App) {
-        app.add_systems(Last, Self::check_actions::<()>);
-        app.world_mut()
-            .register_component_hooks::<CurrentAction>()
-            .on_remove(CurrentAction::on_remove_hook);
-        app.world_mut()
-            .register_component_hooks::<ActionQueue>()
-            .on_remove(ActionQueue::on_remove_hook);
+        app.add_systems(
+            self.schedule,
+            SequentialActionsPlugin::check_actions::<F>.in_set(SequentialActionsSet),
+        );
+        app.add_observer(SequentialActionsPlugin::on_finish_action::<F>);
+
+        match self.cleanup {
+            CleanupMode::Hooks => {
+                app.world_mut()
+                    .register_component_hooks::<CurrentAction>()
+                    .try_on_remove(CurrentAction::on_remove_hook);
+                app.world_mut()
+                    .register_component_hooks::<ActionQueue>()
+                    .try_on_remove(ActionQueue::on_remove_hook);
+            }
+            CleanupMode::Observers => {
+                app.add_observer(CurrentAction::on_remove_trigger::<F>)
+                    .add_observer(ActionQueue::on_remove_trigger::<F>);
+            }
+            CleanupMode::None => {}
+        }
     }
 }
 
 impl SequentialActionsPlugin {
     /// The [`System`] used by [`SequentialActionsPlugin`].
-    /// It is responsible for checking all agents for finished actions
+    /// It is responsible for [`polling`](Action::poll) all agents for finished actions
     /// and advancing the action queue.
+    /// Actions that are not [`polled`](Action::is_polled) are skipped,
+    /// and so are [`idle`](Idle) agents.
     ///
     /// The query filter `F` is used for filtering agents.
     /// Use the unit type `()` for no filtering.
@@ -43,25 +172,151 @@ impl SequentialActionsPlugin {
     /// # }
     /// ```
     pub fn check_actions<F: QueryFilter>(
-        action_q: Query<(Entity, &CurrentAction), F>,
+        action_q: Query<AgentData, (Without<Idle>, F)>,
         world: &World,
         mut commands: Commands,
     ) {
         action_q
             .iter()
             .filter_map(|(agent, current_action)| {
-                current_action
-                    .as_ref()
-                    .and_then(|action| action.is_finished(agent, world).then_some(agent))
+                Self::poll_current_action(agent, current_action, world)
+                    .map(|status| (agent, status))
             })
-            .for_each(|agent| {
+            .for_each(|(agent, status)| {
                 commands.queue(move |world: &mut World| {
-                    Self::stop_current_action(agent, StopReason::Finished, world);
-                    Self::start_next_action(agent, world);
+                    Self::advance_current_action(agent, status, world);
                 });
             });
     }
 
+    /// A parallel variant of [`check_actions`](Self::check_actions)
+    /// that polls all agents using [`par_iter`](Query::par_iter).
+    ///
+    /// Finished agents are collected into a thread-local list,
+    /// and the action queues are then advanced in a single batched command.
+    /// Since agents are polled in parallel,
+    /// the order in which the action queues are advanced is unspecified.
+    ///
+    /// Polling only runs in parallel with the `multi_threaded` feature of [`bevy_ecs`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_sequential_actions::*;
+    /// #
+    /// # fn main() {
+    /// App::new()
+    ///     .add_systems(Last, SequentialActionsPlugin::check_actions_parallel::<()>)
+    ///     .run();
+    /// # }
+    /// ```
+    pub fn check_actions_parallel<F: QueryFilter>(
+        action_q: Query<AgentData, (Without<Idle>, F)>,
+        world: &World,
+        mut finished: Local<Parallel<Vec<(Entity, ActionStatus)>>>,
+        mut commands: Commands,
+    ) {
+        action_q.par_iter().for_each(|(agent, current_action)| {
+            if let Some(status) = Self::poll_current_action(agent, current_action, world) {
+                finished.borrow_local_mut().push((agent, status));
+            }
+        });
+
+        let finished = finished.drain().collect::<Vec<_>>();
+        if finished.is_empty() {
+            return;
+        }
+
+        commands.queue(move |world: &mut World| {
+            for (agent, status) in finished {
+                Self::advance_current_action(agent, status, world);
+            }
+        });
+    }
+
+    /// An exclusive variant of [`check_actions`](Self::check_actions)
+    /// that advances the action queues directly instead of through [`Commands`].
+    ///
+    /// Finished agents are collected into a reusable buffer,
+    /// and then advanced in one pass in the same order as they were polled.
+    /// The `check_actions` benchmark shows no allocations per frame for either system,
+    /// as commands are stored inline in the command queue,
+    /// and no consistent difference in frame time when every agent finishes each frame.
+    /// Since the system cannot run in parallel with other systems,
+    /// measure with the benchmark to see whether it is faster for your use case.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_sequential_actions::*;
+    /// #
+    /// # fn main() {
+    /// App::new()
+    ///     .add_systems(Last, SequentialActionsPlugin::check_actions_exclusive::<()>)
+    ///     .run();
+    /// # }
+    /// ```
+    pub fn check_actions_exclusive<F: QueryFilter>(
+        world: &mut World,
+        mut finished: Local<Vec<(Entity, ActionStatus)>>,
+        mut action_q: Local<QueryState<AgentData, (Without<Idle>, F)>>,
+    ) {
+        finished.extend(action_q.iter(world).filter_map(|(agent, current_action)| {
+            Self::poll_current_action(agent, current_action, world).map(|status| (agent, status))
+        }));
+
+        for (agent, status) in finished.drain(..) {
+            Self::advance_current_action(agent, status, world);
+        }
+    }
+
+    /// [`Polls`](Action::poll) the current action of `agent`,
+    /// and returns the status if it is no longer [`running`](ActionStatus::Running).
+    fn poll_current_action(
+        agent: Entity,
+        current_action: &CurrentAction,
+        world: &World,
+    ) -> Option<ActionStatus> {
+        current_action
+            .as_ref()
+            .filter(|action| action.is_polled())
+            .map(|action| action.poll(agent, world))
+            .filter(|status| *status != ActionStatus::Running)
+    }
+
+    /// Advances the action queue for `agent` based on the polled `status` of the current action.
+    fn advance_current_action(agent: Entity, status: ActionStatus, world: &mut World) {
+        match status.stop_reason() {
+            Some(reason) => {
+                Self::stop_current_action(agent, reason, world);
+                Self::start_next_action(agent, world);
+            }
+            None => Self::step_current_action(agent, world),
+        }
+    }
+
+    /// [`Observer`] used by [`SequentialActionsPlugin`] for the [`FinishAction`] event.
+    /// It [`finishes`](Self::finish_action) the current action of the targeted `agent`,
+    /// but only if it matches the query filter `F`.
+    pub fn on_finish_action<F: QueryFilter>(
+        finish: On<FinishAction>,
+        agent_q: Query<(), F>,
+        mut commands: Commands,
+    ) {
+        let agent = finish.agent;
+        if !agent_q.contains(agent) {
+            return;
+        }
+
+        commands.queue(move |world: &mut World| {
+            Self::finish_action(agent, world);
+        });
+    }
+
     /// Adds a single [`action`](Action) to `agent` with specified `config`.
     pub fn add_action(
         agent: Entity,
@@ -69,14 +324,38 @@ impl SequentialActionsPlugin {
         action: impl IntoBoxedAction,
         world: &mut World,
     ) {
-        let mut action = action.into_boxed_action();
+        Self::insert_action(agent, config, action.into_boxed_action(), None, world);
+    }
 
+    /// Adds a single [`action`](Action) with specified `id` to `agent` with specified `config`.
+    pub fn add_action_with_id(
+        agent: Entity,
+        config: AddConfig,
+        id: ActionId,
+        action: impl IntoBoxedAction,
+        world: &mut World,
+    ) {
+        Self::insert_action(agent, config, action.into_boxed_action(), Some(id), world);
+    }
+
+    /// Adds a single [`action`](Action) with an optional `id` to `agent` with specified `config`.
+    fn insert_action(
+        agent: Entity,
+        config: AddConfig,
+        mut action: BoxedAction,
+        id: Option<ActionId>,
+        world: &mut World,
+    ) {
         if world.get_entity(agent).is_err() {
             warn!("Cannot add action {action:?} to non-existent agent {agent}.");
             return;
         }
 
         debug!("Adding action {action:?} for agent {agent} with {config:?}.");
+        if config.order == AddOrder::Replace {
+            Self::clear_actions_with_reason(agent, DropReason::Replaced, world);
+        }
+
         action.on_add(agent, world);
 
         let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
@@ -84,8 +363,7 @@ impl SequentialActionsPlugin {
                 "Cannot enqueue action {action:?} to non-existent agent {agent}. \
                 Action is therefore dropped immediately."
             );
-            action.on_remove(None, world);
-            action.on_drop(None, world, DropReason::Skipped);
+            Self::drop_action(action, None, DropReason::Skipped, world);
             return;
         };
 
@@ -95,15 +373,12 @@ impl SequentialActionsPlugin {
                 Action is therefore dropped immediately.",
                 std::any::type_name::<ActionQueue>()
             );
-            action.on_remove(Some(agent), world);
-            action.on_drop(Some(agent), world, DropReason::Skipped);
+            Self::drop_action(action, Some(agent), DropReason::Skipped, world);
             return;
         };
 
-        match config.order {
-            AddOrder::Back => action_queue.push_back(action),
-            AddOrder::Front => action_queue.push_front(action),
-        }
+        let index = action_queue.order_index(config.order);
+        action_queue.insert_with_id(index, action, id);
 
         if config.start {
             let Some(current_action) = agent_ref.get::<CurrentAction>() else {
@@ -126,7 +401,7 @@ impl SequentialActionsPlugin {
     where
         I: DoubleEndedIterator<Item = BoxedAction> + ExactSizeIterator + Debug,
     {
-        let actions = actions.into_iter();
+        let mut actions = actions.into_iter();
         let len = actions.len();
 
         if len == 0 {
@@ -148,9 +423,14 @@ impl SequentialActionsPlugin {
 
         debug!("Adding actions {actions:?} for agent {agent} with {config:?}.");
         action_queue.reserve(len);
+        let mut index = action_queue.order_index(config.order);
+
+        if config.order == AddOrder::Replace {
+            Self::clear_actions_with_reason(agent, DropReason::Replaced, world);
+        }
 
         match config.order {
-            AddOrder::Back => {
+            AddOrder::Back | AddOrder::Replace => {
                 for mut action in actions {
                     action.on_add(agent, world);
 
@@ -159,8 +439,7 @@ impl SequentialActionsPlugin {
                             "Cannot enqueue action {action:?} to non-existent agent {agent}. \
                             Action is therefore dropped immediately."
                         );
-                        action.on_remove(None, world);
-                        action.on_drop(None, world, DropReason::Skipped);
+                        Self::drop_action(action, None, DropReason::Skipped, world);
                         return;
                     };
 
@@ -170,16 +449,24 @@ impl SequentialActionsPlugin {
                             Action is therefore dropped immediately.",
                             std::any::type_name::<ActionQueue>()
                         );
-                        action.on_remove(Some(agent), world);
-                        action.on_drop(Some(agent), world, DropReason::Skipped);
+                        Self::drop_action(action, Some(agent), DropReason::Skipped, world);
                         return;
                     };
 
-                    action_queue.push_back(action);
+                    let index = action_queue.len();
+                    action_queue.insert_with_id(index, action, None);
                 }
             }
-            AddOrder::Front => {
-                for mut action in actions.rev() {
+            AddOrder::Front | AddOrder::Index(_) | AddOrder::AfterType(_) => {
+                // Actions are added in reverse to the front,
+                // and in order at the computed index otherwise
+                let front = config.order == AddOrder::Front;
+
+                while let Some(mut action) = if front {
+                    actions.next_back()
+                } else {
+                    actions.next()
+                } {
                     action.on_add(agent, world);
 
                     let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
@@ -187,8 +474,7 @@ impl SequentialActionsPlugin {
                             "Cannot enqueue action {action:?} to non-existent agent {agent}. \
                             Action is therefore dropped immediately."
                         );
-                        action.on_remove(None, world);
-                        action.on_drop(None, world, DropReason::Skipped);
+                        Self::drop_action(action, None, DropReason::Skipped, world);
                         return;
                     };
 
@@ -198,12 +484,16 @@ impl SequentialActionsPlugin {
                             Action is therefore dropped immediately.",
                             std::any::type_name::<ActionQueue>()
                         );
-                        action.on_remove(Some(agent), world);
-                        action.on_drop(Some(agent), world, DropReason::Skipped);
+                        Self::drop_action(action, Some(agent), DropReason::Skipped, world);
                         return;
                     };
 
-                    action_queue.push_front(action);
+                    index = index.min(action_queue.len());
+                    action_queue.insert_with_id(index, action, None);
+
+                    if !front {
+                        index += 1;
+                    }
                 }
             }
         }
@@ -223,6 +513,24 @@ impl SequentialActionsPlugin {
         }
     }
 
+    /// Interrupts the current [`action`](Action) for `agent` with a collection of actions.
+    /// An empty collection does nothing.
+    ///
+    /// The current action is [`stopped`](Action::on_stop) as [`paused`](StopReason::Paused),
+    /// and the new actions are added to the front of the queue and started immediately.
+    pub fn interrupt_actions<I>(agent: Entity, actions: I, world: &mut World)
+    where
+        I: DoubleEndedIterator<Item = BoxedAction> + ExactSizeIterator + Debug,
+    {
+        if actions.len() == 0 {
+            return;
+        }
+
+        debug!("Interrupting current action for agent {agent} with actions {actions:?}.");
+        Self::stop_current_action(agent, StopReason::Paused, world);
+        Self::add_actions(agent, AddConfig::new(true, AddOrder::Front), actions, world);
+    }
+
     /// [`Starts`](Action::on_start) the next [`action`](Action) in the queue for `agent`,
     /// but only if there is no current action.
     pub fn execute_actions(agent: Entity, world: &mut World) {
@@ -246,6 +554,9 @@ impl SequentialActionsPlugin {
     }
 
     /// [`Stops`](Action::on_stop) the current [`action`](Action) for `agent` with specified `reason`.
+    ///
+    /// A [`failed`](StopReason::Failed) or [`timed out`](StopReason::TimedOut) action
+    /// is handled according to the [`OnFailure`] policy of `agent`.
     pub fn stop_current_action(agent: Entity, reason: StopReason, world: &mut World) {
         let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
             warn!(
@@ -263,43 +574,171 @@ impl SequentialActionsPlugin {
             return;
         };
 
-        if let Some(mut action) = current_action.take() {
+        if let Some((mut action, id)) = current_action.take_with_id() {
+            agent_ref.insert(Idle);
+
             debug!("Stopping current action {action:?} for agent {agent} with reason {reason:?}.");
             action.on_stop(Some(agent), world, reason);
+            Self::trigger_stopped(agent, &action, reason, world);
+
+            let drop_reason = match reason {
+                StopReason::Failed => DropReason::Failed,
+                StopReason::TimedOut => DropReason::TimedOut,
+                _ => DropReason::Done,
+            };
 
-            match reason {
-                StopReason::Finished | StopReason::Canceled => {
-                    action.on_remove(Some(agent), world);
-                    action.on_drop(Some(agent), world, DropReason::Done);
+            let on_failure = match drop_reason {
+                DropReason::Failed | DropReason::TimedOut => {
+                    world.get::<OnFailure>(agent).copied().unwrap_or_default()
                 }
-                StopReason::Paused => {
+                _ => OnFailure::default(),
+            };
+
+            match (reason, on_failure) {
+                (StopReason::Paused, _)
+                | (StopReason::Failed | StopReason::TimedOut, OnFailure::Retry) => {
                     let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
                         warn!(
-                            "Cannot enqueue paused action {action:?} to non-existent agent {agent}. \
+                            "Cannot enqueue {reason:?} action {action:?} to non-existent agent {agent}. \
                             Action is therefore dropped immediately."
                         );
-                        action.on_remove(None, world);
-                        action.on_drop(None, world, DropReason::Skipped);
+                        Self::drop_action(action, None, DropReason::Skipped, world);
                         return;
                     };
 
                     let Some(mut action_queue) = agent_ref.get_mut::<ActionQueue>() else {
                         warn!(
-                            "Cannot enqueue paused action {action:?} to agent {agent} due to missing component {}. \
+                            "Cannot enqueue {reason:?} action {action:?} to agent {agent} due to missing component {}. \
                             Action is therefore dropped immediately.",
                             std::any::type_name::<ActionQueue>()
                         );
-                        action.on_remove(Some(agent), world);
-                        action.on_drop(Some(agent), world, DropReason::Skipped);
+                        Self::drop_action(action, Some(agent), DropReason::Skipped, world);
                         return;
                     };
 
-                    action_queue.push_front(action);
+                    action_queue.insert_with_id(0, action, id);
+                }
+                (_, OnFailure::Clear) => {
+                    Self::drop_action(action, Some(agent), drop_reason, world);
+                    Self::clear_actions(agent, world);
+                }
+                _ => {
+                    Self::drop_action(action, Some(agent), drop_reason, world);
                 }
             }
         }
     }
 
+    /// [`Steps`](Action::on_step) the current [`action`](Action) for `agent`.
+    ///
+    /// The action is only stepped once, and is [`polled`](Action::poll) again afterwards.
+    /// If it has finished, it is [`stopped`](Action::on_stop)
+    /// and the next action in the queue is [`started`](Action::on_start).
+    /// Otherwise, it is polled as usual next frame.
+    pub fn step_current_action(agent: Entity, world: &mut World) {
+        let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
+            warn!("Cannot step current action for non-existent agent {agent}.");
+            return;
+        };
+
+        let Some(mut current_action) = agent_ref.get_mut::<CurrentAction>() else {
+            warn!(
+                "Cannot step current action for agent {agent} due to missing component {}.",
+                std::any::type_name::<CurrentAction>()
+            );
+            return;
+        };
+
+        let Some((mut action, id)) = current_action.take_with_id() else {
+            return;
+        };
+
+        debug!("Stepping current action {action:?} for agent {agent}.");
+        action.on_step(agent, world);
+
+        let status = world
+            .get::<CurrentAction>(agent)
+            .map(|_| action.poll(agent, world));
+
+        let (Some(status), Some(mut current_action)) =
+            (status, world.get_mut::<CurrentAction>(agent))
+        else {
+            debug!("Canceling action {action:?} due to missing agent {agent}.");
+            action.on_stop(None, world, StopReason::Canceled);
+            Self::drop_action(action, None, DropReason::Done, world);
+            return;
+        };
+
+        current_action.set(action, id);
+
+        if let Some(reason) = status.stop_reason() {
+            Self::stop_current_action(agent, reason, world);
+            Self::start_next_action(agent, world);
+        }
+    }
+
+    /// [`Finishes`](Action::on_finish) the current [`action`](Action) for `agent`.
+    ///
+    /// If the action is done, it is [`stopped`](Action::on_stop) as [`finished`](StopReason::Finished),
+    /// and the next action in the queue is [`started`](Action::on_start).
+    /// Nothing happens if `agent` has no current action.
+    pub fn finish_action(agent: Entity, world: &mut World) {
+        let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
+            warn!("Cannot finish current action for non-existent agent {agent}.");
+            return;
+        };
+
+        let Some(mut current_action) = agent_ref.get_mut::<CurrentAction>() else {
+            warn!(
+                "Cannot finish current action for agent {agent} due to missing component {}.",
+                std::any::type_name::<CurrentAction>()
+            );
+            return;
+        };
+
+        let Some((mut action, id)) = current_action.take_with_id() else {
+            return;
+        };
+
+        debug!("Finishing current action {action:?} for agent {agent}.");
+        let finished = action.on_finish(agent, world);
+
+        let Some(mut current_action) = world.get_mut::<CurrentAction>(agent) else {
+            debug!("Canceling action {action:?} due to missing agent {agent}.");
+            action.on_stop(None, world, StopReason::Canceled);
+            Self::drop_action(action, None, DropReason::Done, world);
+            return;
+        };
+
+        current_action.set(action, id);
+
+        if finished {
+            Self::stop_current_action(agent, StopReason::Finished, world);
+            Self::start_next_action(agent, world);
+        }
+    }
+
+    /// [`Stops`](Action::on_stop) the current [`action`](Action) for `agent` as [`canceled`](StopReason::Canceled),
+    /// but only if it has the specified `id`.
+    pub fn cancel_action_if_current(agent: Entity, id: ActionId, world: &mut World) {
+        let Ok(agent_ref) = world.get_entity(agent) else {
+            warn!("Cannot cancel action with {id:?} for non-existent agent {agent}.");
+            return;
+        };
+
+        let Some(current_action) = agent_ref.get::<CurrentAction>() else {
+            warn!(
+                "Cannot cancel action with {id:?} for agent {agent} due to missing component {}.",
+                std::any::type_name::<CurrentAction>()
+            );
+            return;
+        };
+
+        if current_action.id() == Some(id) {
+            Self::stop_current_action(agent, StopReason::Canceled, world);
+        }
+    }
+
     /// [`Starts`](Action::on_start) the next [`action`](Action) in the queue for `agent`.
     ///
     /// This will loop until any next action is not immediately finished or the queue is empty.
@@ -342,7 +781,8 @@ impl SequentialActionsPlugin {
                 break;
             };
 
-            let Some(mut action) = action_queue.pop_front() else {
+            let Some((mut action, id)) = action_queue.remove_with_id(0) else {
+                world.trigger(QueueEmptied { agent });
                 break;
             };
 
@@ -350,28 +790,38 @@ impl SequentialActionsPlugin {
             if !action.on_start(agent, world) {
                 match world.get_mut::<CurrentAction>(agent) {
                     Some(mut current_action) => {
-                        current_action.0 = Some(action);
+                        let name = action.type_name();
+                        current_action.set(action, id);
+                        world.entity_mut(agent).remove::<Idle>();
+                        world.trigger(ActionStarted {
+                            agent,
+                            action: name,
+                        });
                     }
                     None => {
                         debug!("Canceling action {action:?} due to missing agent {agent}.");
                         action.on_stop(None, world, StopReason::Canceled);
-                        action.on_remove(None, world);
-                        action.on_drop(None, world, DropReason::Done);
+                        Self::drop_action(action, None, DropReason::Done, world);
                     }
                 }
                 break;
             };
 
             debug!("Finishing action {action:?} for agent {agent}.");
-            let agent = world.get_entity(agent).map(|_| agent).ok();
-            action.on_stop(agent, world, StopReason::Finished);
-            action.on_remove(agent, world);
-            action.on_drop(agent, world, DropReason::Done);
-
-            if agent.is_none() {
+            if world.get_entity(agent).is_err() {
+                action.on_stop(None, world, StopReason::Finished);
+                Self::drop_action(action, None, DropReason::Done, world);
                 break;
             }
 
+            world.trigger(ActionStarted {
+                agent,
+                action: action.type_name(),
+            });
+            action.on_stop(Some(agent), world, StopReason::Finished);
+            Self::trigger_stopped(agent, &action, StopReason::Finished, world);
+            Self::drop_action(action, Some(agent), DropReason::Done, world);
+
             #[cfg(debug_assertions)]
             {
                 counter += 1;
@@ -402,21 +852,175 @@ impl SequentialActionsPlugin {
                 break;
             };
 
-            let Some(mut action) = action_queue.pop_front() else {
+            let Some((action, _)) = action_queue.remove_with_id(0) else {
                 break;
             };
 
             debug!("Skipping action {action:?} for agent {agent}.");
-            action.on_remove(Some(agent), world);
-            action.on_drop(Some(agent), world, DropReason::Skipped);
+            Self::drop_action(action, Some(agent), DropReason::Skipped, world);
 
             n -= 1;
         }
     }
 
+    /// Removes the [`action`](Action) with specified `id` from the queue for `agent`.
+    /// The action is [`dropped`](Action::on_drop) as [`skipped`](DropReason::Skipped).
+    pub fn remove_action(agent: Entity, id: ActionId, world: &mut World) {
+        let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
+            warn!("Cannot remove action with {id:?} for non-existent agent {agent}.");
+            return;
+        };
+
+        let Some(mut action_queue) = agent_ref.get_mut::<ActionQueue>() else {
+            warn!(
+                "Cannot remove action with {id:?} for agent {agent} due to missing component {}.",
+                std::any::type_name::<ActionQueue>()
+            );
+            return;
+        };
+
+        let Some((action, _)) = action_queue
+            .position(id)
+            .and_then(|index| action_queue.remove_with_id(index))
+        else {
+            return;
+        };
+
+        debug!("Removing action {action:?} with {id:?} for agent {agent}.");
+        Self::drop_action(action, Some(agent), DropReason::Skipped, world);
+    }
+
+    /// Retains only the actions in the queue for `agent` for which `f` returns `true`.
+    /// The other actions are [`dropped`](Action::on_drop) as [`skipped`](DropReason::Skipped).
+    ///
+    /// This will walk the queue from front to back and remove one action at a time.
+    /// Actions added to the back of the queue while dropping are therefore also checked,
+    /// while actions added in front of the current position are not.
+    ///
+    /// Since an action may add itself again to the back of the queue while dropping,
+    /// this may trigger an infinite loop.
+    /// A counter is therefore used in debug build
+    /// that panics when reaching a sufficient target.
+    pub fn retain_actions(
+        agent: Entity,
+        mut f: impl FnMut(&dyn Action) -> bool,
+        world: &mut World,
+    ) {
+        let mut index = 0;
+
+        #[cfg(debug_assertions)]
+        let mut counter: u16 = 0;
+
+        loop {
+            let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
+                warn!("Cannot retain actions for non-existent agent {agent}.");
+                break;
+            };
+
+            let Some(mut action_queue) = agent_ref.get_mut::<ActionQueue>() else {
+                warn!(
+                    "Cannot retain actions for agent {agent} due to missing component {}.",
+                    std::any::type_name::<ActionQueue>()
+                );
+                break;
+            };
+
+            let Some(action) = action_queue.get(index) else {
+                break;
+            };
+
+            if f(action.as_ref()) {
+                index += 1;
+                continue;
+            }
+
+            let Some((action, _)) = action_queue.remove_with_id(index) else {
+                break;
+            };
+
+            debug!("Removing action {action:?} for agent {agent}.");
+            Self::drop_action(action, Some(agent), DropReason::Skipped, world);
+
+            #[cfg(debug_assertions)]
+            {
+                counter += 1;
+                if counter == u16::MAX {
+                    panic!("infinite loop detected when retaining actions");
+                }
+            }
+        }
+    }
+
+    /// Moves the [`action`](Action) at index `from` to index `to` in the queue for `agent`.
+    ///
+    /// An index `to` beyond the queue moves the action to the back.
+    pub fn move_action(agent: Entity, from: usize, to: usize, world: &mut World) {
+        let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
+            warn!("Cannot move action for non-existent agent {agent}.");
+            return;
+        };
+
+        let Some(mut action_queue) = agent_ref.get_mut::<ActionQueue>() else {
+            warn!(
+                "Cannot move action for agent {agent} due to missing component {}.",
+                std::any::type_name::<ActionQueue>()
+            );
+            return;
+        };
+
+        let Some((action, id)) = action_queue.remove_with_id(from) else {
+            warn!(
+                "Cannot move action at index {from} for agent {agent} \
+                as the queue only has {} actions.",
+                action_queue.len()
+            );
+            return;
+        };
+
+        debug!("Moving action {action:?} from index {from} to {to} for agent {agent}.");
+        let to = to.min(action_queue.len());
+        action_queue.insert_with_id(to, action, id);
+    }
+
+    /// Swaps the [`actions`](Action) at index `a` and `b` in the queue for `agent`.
+    pub fn swap_actions(agent: Entity, a: usize, b: usize, world: &mut World) {
+        let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
+            warn!("Cannot swap actions for non-existent agent {agent}.");
+            return;
+        };
+
+        let Some(mut action_queue) = agent_ref.get_mut::<ActionQueue>() else {
+            warn!(
+                "Cannot swap actions for agent {agent} due to missing component {}.",
+                std::any::type_name::<ActionQueue>()
+            );
+            return;
+        };
+
+        let len = action_queue.len();
+        if a >= len || b >= len {
+            warn!(
+                "Cannot swap actions at index {a} and {b} for agent {agent} \
+                as the queue only has {len} actions."
+            );
+            return;
+        }
+
+        debug!("Swapping actions at index {a} and {b} for agent {agent}.");
+        action_queue.swap_with_ids(a, b);
+    }
+
+    /// Returns the [`status`](ActionIdStatus) of the [`action`](Action) with specified `id` for `agent`.
+    pub fn action_status(agent: Entity, id: ActionId, world: &World) -> ActionIdStatus {
+        world
+            .actions_ref(agent)
+            .map_or(ActionIdStatus::Unknown, |actions| actions.status(id))
+    }
+
     /// Clears the action queue for `agent`.
     ///
     /// Current action is [`stopped`](Action::on_stop) as [`canceled`](StopReason::Canceled).
+    /// The [`Blackboard`] of `agent` is also cleared if it [`resets on clear`](Blackboard::reset_on_clear).
     ///
     /// This will loop and remove one action at a time.
     /// Since you can add new actions to the queue between each removal,
@@ -424,6 +1028,11 @@ impl SequentialActionsPlugin {
     /// A counter is therefore used in debug build
     /// that panics when reaching a sufficient target.
     pub fn clear_actions(agent: Entity, world: &mut World) {
+        Self::clear_actions_with_reason(agent, DropReason::Cleared, world);
+    }
+
+    /// Clears the action queue for `agent` with specified drop `reason`.
+    fn clear_actions_with_reason(agent: Entity, reason: DropReason, world: &mut World) {
         let Ok(mut agent_ref) = world.get_entity_mut(agent) else {
             warn!("Cannot clear actions for non-existent agent {agent}.");
             return;
@@ -438,11 +1047,13 @@ impl SequentialActionsPlugin {
             return;
         };
 
-        if let Some(mut current_action) = current_action.take() {
+        if let Some((mut current_action, _)) = current_action.take_with_id() {
+            agent_ref.insert(Idle);
+
             debug!("Clearing current action {current_action:?} for agent {agent}.");
             current_action.on_stop(Some(agent), world, StopReason::Canceled);
-            current_action.on_remove(Some(agent), world);
-            current_action.on_drop(Some(agent), world, DropReason::Cleared);
+            Self::trigger_stopped(agent, &current_action, StopReason::Canceled, world);
+            Self::drop_action(current_action, Some(agent), reason, world);
         }
 
         // Clear action queue
@@ -463,13 +1074,12 @@ impl SequentialActionsPlugin {
                 return;
             };
 
-            let Some(mut action) = action_queue.pop_front() else {
+            let Some((action, _)) = action_queue.remove_with_id(0) else {
                 break;
             };
 
             debug!("Clearing action {action:?} from the queue for agent {agent}.");
-            action.on_remove(Some(agent), world);
-            action.on_drop(Some(agent), world, DropReason::Cleared);
+            Self::drop_action(action, Some(agent), reason, world);
 
             #[cfg(debug_assertions)]
             {
@@ -479,5 +1089,46 @@ impl SequentialActionsPlugin {
                 }
             }
         }
+
+        // Reset blackboard
+        if let Some(mut blackboard) = world.get_mut::<Blackboard>(agent)
+            && blackboard.resets_on_clear()
+        {
+            blackboard.clear();
+        }
+    }
+
+    /// [`Removes`](Action::on_remove) and [`drops`](Action::on_drop) an [`action`](Action),
+    /// and triggers [`ActionDropped`] if `agent` still exists.
+    fn drop_action(
+        mut action: BoxedAction,
+        agent: Option<Entity>,
+        reason: DropReason,
+        world: &mut World,
+    ) {
+        let name = action.type_name();
+        action.on_remove(agent, world);
+        action.on_drop(agent, world, reason);
+
+        if let Some(agent) = agent
+            && world.get_entity(agent).is_ok()
+        {
+            world.trigger(ActionDropped {
+                agent,
+                action: name,
+                reason,
+            });
+        }
+    }
+
+    /// Triggers [`ActionStopped`] if `agent` still exists.
+    fn trigger_stopped(agent: Entity, action: &BoxedAction, reason: StopReason, world: &mut World) {
+        if world.get_entity(agent).is_ok() {
+            world.trigger(ActionStopped {
+                agent,
+                action: action.type_name(),
+                reason,
+            });
+        }
     }
 }
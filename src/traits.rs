@@ -73,7 +73,7 @@ use super::*;
 ///   #   fn on_stop(&mut self, _a: Option<Entity>, _w: &mut World, _r: StopReason) {}
 ///   fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
 ///       match reason {
-///           DropReason::Done => {
+///           DropReason::Done | DropReason::Failed | DropReason::TimedOut => {
 ///               // ...
 ///           }
 ///           DropReason::Skipped => {
@@ -84,6 +84,10 @@ use super::*;
 ///               // All new actions added to the queue here
 ///               // will also be cleared
 ///           }
+///           DropReason::Replaced => {
+///               // All new actions added to the queue here
+///               // will also be replaced
+///           }
 ///       }
 ///   }
 ///   # }
@@ -93,9 +97,69 @@ pub trait Action: downcast_rs::Downcast + Send + Sync + 'static {
     /// Determines if an action is finished or not.
     /// Advances the action queue when returning `true`.
     ///
-    /// By default, this method is called every frame in the [`Last`] schedule.
+    /// By default, this method is called every frame in the [`Last`] schedule
+    /// through [`poll`](Self::poll).
     fn is_finished(&self, agent: Entity, world: &World) -> bool;
 
+    /// Determines the [`outcome`](ActionStatus) of an action.
+    /// Advances the action queue when the action has [`finished`](ActionStatus::is_finished).
+    /// Returning [`ActionStatus::Step`] calls [`on_step`](Self::on_step) instead.
+    ///
+    /// A [`failed`](ActionStatus::Failed) action is [`stopped`](Self::on_stop)
+    /// and [`dropped`](Self::on_drop) as failed instead of finished.
+    /// Since [`on_start`](Self::on_start) can only finish an action successfully,
+    /// an action that fails right away should instead return `false` there
+    /// and report the failure here.
+    ///
+    /// By default, this method is called every frame in the [`Last`] schedule,
+    /// and simply returns [`ActionStatus::Succeeded`] when [`is_finished`](Self::is_finished).
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        if self.is_finished(agent, world) {
+            ActionStatus::Succeeded
+        } else {
+            ActionStatus::Running
+        }
+    }
+
+    /// The method that is called when [`poll`](Self::poll) returns [`ActionStatus::Step`].
+    ///
+    /// Actions that run other actions in steps advance to their next step here,
+    /// such as [`ActionSequence`] starting its next action.
+    /// This way, the steps are run within the action itself,
+    /// and work the same whether the action is in the queue or nested inside another action.
+    /// The action is [`polled`](Self::poll) again afterwards,
+    /// but is stepped at most once per frame.
+    fn on_step(&mut self, agent: Entity, world: &mut World) {}
+
+    /// Determines whether an action should be [`polled`](Self::poll) every frame.
+    ///
+    /// Event-driven actions can return `false` here to skip polling entirely,
+    /// and instead be completed with either [`finish`](ManageActions::finish)
+    /// or the [`FinishAction`] event.
+    ///
+    /// [`RepeatAction`] and [`Then`] forward this to their current action,
+    /// and [`finish`](Self::on_finish) it when signaled.
+    /// Other actions that run actions in parallel or check them on their own,
+    /// such as [`ParallelActions`] or [`Timeout`], always poll their actions.
+    ///
+    /// By default, this method returns `true`.
+    fn is_polled(&self) -> bool {
+        true
+    }
+
+    /// The method that is called when an action is signaled as finished,
+    /// either with [`finish`](ManageActions::finish) or the [`FinishAction`] event.
+    ///
+    /// Returning `true` [`stops`](Self::on_stop) the action as [`finished`](StopReason::Finished)
+    /// and advances the action queue.
+    /// Actions that run other actions in steps can instead finish their current action here,
+    /// and return `false` if they are not done yet.
+    ///
+    /// By default, this method returns `true`.
+    fn on_finish(&mut self, agent: Entity, world: &mut World) -> bool {
+        true
+    }
+
     /// The method that is called when an action is started.
     ///
     /// Typically here you would insert components to `agent` or a new entity
@@ -160,6 +224,46 @@ where
     fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
 }
 
+/// An [`Action`] that produces an output when finished.
+///
+/// The output can be passed on to a following action using [`then`](Self::then).
+pub trait ActionWithOutput: Action {
+    /// The type of the output.
+    type Output: Send + 'static;
+
+    /// Takes the output of the action.
+    ///
+    /// This method is called after the action has been [`stopped`](Action::on_stop) as
+    /// [`finished`](StopReason::Finished), so the output is typically stored there.
+    /// Returning `None` means there is no output.
+    fn take_output(&mut self, agent: Entity, world: &mut World) -> Option<Self::Output>;
+
+    /// Creates the next action from the output of this action using `f`.
+    ///
+    /// See [`Then`] for more information.
+    fn then<B, F>(self, f: F) -> Then<B::Output>
+    where
+        Self: Sized,
+        B: ActionWithOutput,
+        F: FnOnce(Self::Output) -> B + Send + Sync + 'static,
+    {
+        Then::new(self, f)
+    }
+
+    /// Creates the next action from the output of this action using `f`,
+    /// for actions without an output.
+    ///
+    /// See [`Then`] for more information.
+    fn then_action<B, F>(self, f: F) -> Then<()>
+    where
+        Self: Sized,
+        B: IntoBoxedAction,
+        F: FnOnce(Self::Output) -> B + Send + Sync + 'static,
+    {
+        Then::new_action(self, f)
+    }
+}
+
 /// Extension method for managing actions.
 /// Implemented for both [`Commands`] and [`World`].
 pub trait ActionsProxy {
@@ -185,6 +289,21 @@ pub trait ManageActions {
     /// Adds one or more actions to the queue.
     fn add(&mut self, actions: impl IntoBoxedActions) -> &mut Self;
 
+    /// Adds a single action to the queue, and returns its [`ActionId`].
+    ///
+    /// The id can be used for [`removing`](Self::remove) or [`canceling`](Self::cancel_if_current)
+    /// this specific action later on.
+    /// Actions that add themselves again in [`on_drop`](Action::on_drop) will not keep their id.
+    fn add_with_id(&mut self, action: impl IntoBoxedAction) -> ActionId;
+
+    /// Interrupts the current action with one or more actions.
+    ///
+    /// The current action is [`stopped`](Action::on_stop) as [`paused`](StopReason::Paused),
+    /// and the new actions are added to the front of the queue and started immediately.
+    /// The paused action will resume once the new actions are done.
+    /// Ignores the current [`config`](AddConfig). An empty collection does nothing.
+    fn interrupt(&mut self, actions: impl IntoBoxedActions) -> &mut Self;
+
     /// [`Starts`](Action::on_start) the next [`action`](Action) in the queue,
     /// but only if there is no current action.
     fn execute(&mut self) -> &mut Self;
@@ -194,19 +313,59 @@ pub trait ManageActions {
     /// Current action is [`stopped`](Action::on_stop) as [`canceled`](StopReason::Canceled).
     fn next(&mut self) -> &mut Self;
 
+    /// [`Stops`](Action::on_stop) the current action as [`finished`](StopReason::Finished),
+    /// and [`starts`](Action::on_start) the next action in the queue.
+    ///
+    /// This is mainly used for completing actions that are not [`polled`](Action::is_polled).
+    fn finish(&mut self) -> &mut Self;
+
     /// [`Stops`](Action::on_stop) the current action as [`canceled`](StopReason::Canceled).
     ///
     /// To resume the action queue, call either [`execute`](Self::execute) or [`next`](Self::next).
     fn cancel(&mut self) -> &mut Self;
 
+    /// [`Stops`](Action::on_stop) the current action as [`canceled`](StopReason::Canceled),
+    /// but only if it has the specified `id`.
+    ///
+    /// To resume the action queue, call either [`execute`](Self::execute) or [`next`](Self::next).
+    fn cancel_if_current(&mut self, id: ActionId) -> &mut Self;
+
     /// [`Stops`](Action::on_stop) the current action as [`paused`](StopReason::Paused).
     ///
     /// To resume the action queue, call either [`execute`](Self::execute) or [`next`](Self::next).
     fn pause(&mut self) -> &mut Self;
 
+    /// Moves the action at index `from` to index `to` in the queue.
+    ///
+    /// An index `to` beyond the queue moves the action to the back.
+    fn move_to(&mut self, from: usize, to: usize) -> &mut Self;
+
+    /// Swaps the actions at index `a` and `b` in the queue.
+    fn swap(&mut self, a: usize, b: usize) -> &mut Self;
+
     /// Skips the next `n` actions in the queue.
     fn skip(&mut self, n: usize) -> &mut Self;
 
+    /// Removes the action with specified `id` from the queue.
+    /// The action is [`dropped`](Action::on_drop) as [`skipped`](DropReason::Skipped).
+    ///
+    /// The current action is not affected, see [`cancel_if_current`](Self::cancel_if_current) for that.
+    fn remove(&mut self, id: ActionId) -> &mut Self;
+
+    /// Retains only the actions in the queue for which `f` returns `true`.
+    /// The other actions are [`dropped`](Action::on_drop) as [`skipped`](DropReason::Skipped).
+    ///
+    /// The current action is not affected.
+    fn retain(&mut self, f: impl FnMut(&dyn Action) -> bool + Send + 'static) -> &mut Self;
+
+    /// Removes all actions of type `T` from the queue.
+    /// The actions are [`dropped`](Action::on_drop) as [`skipped`](DropReason::Skipped).
+    ///
+    /// The current action is not affected.
+    fn remove_all<T: Action>(&mut self) -> &mut Self {
+        self.retain(|action| !action.is::<T>())
+    }
+
     /// Clears the action queue.
     ///
     /// Current action is [`stopped`](Action::on_stop) as [`canceled`](StopReason::Canceled).
@@ -0,0 +1,152 @@
+use bevy_ecs::system::SystemParam;
+
+use super::*;
+
+/// Extension method for inspecting actions.
+/// Implemented for [`World`].
+///
+/// See [`ActionsQuery`] for inspecting actions in a [`System`].
+pub trait ActionsRefProxy {
+    /// Returns a read-only view of the actions for specified `agent`.
+    ///
+    /// Returns `None` if `agent` does not exist or is missing a component.
+    fn actions_ref(&self, agent: Entity) -> Option<AgentActionsRef<'_>>;
+}
+
+impl ActionsRefProxy for World {
+    fn actions_ref(&self, agent: Entity) -> Option<AgentActionsRef<'_>> {
+        let agent_ref = self.get_entity(agent).ok()?;
+        Some(AgentActionsRef::new(
+            agent,
+            agent_ref.get::<CurrentAction>()?,
+            agent_ref.get::<ActionQueue>()?,
+        ))
+    }
+}
+
+/// [`SystemParam`] for inspecting the actions of agents.
+///
+/// The query filter `F` is used for filtering agents.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sequential_actions::*;
+/// #
+/// fn show_plans(actions_q: ActionsQuery) {
+///     for actions in actions_q.iter() {
+///         let current = actions.current_type_name().unwrap_or("idle");
+///         let queued = actions.type_names().collect::<Vec<_>>();
+///         println!("Agent {}: {current} -> {queued:?}", actions.agent());
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct ActionsQuery<'w, 's, F: QueryFilter + 'static = ()> {
+    query: Query<'w, 's, (Entity, &'static CurrentAction, &'static ActionQueue), F>,
+}
+
+impl<F: QueryFilter> ActionsQuery<'_, '_, F> {
+    /// Returns a read-only view of the actions for specified `agent`.
+    ///
+    /// Returns `None` if `agent` does not match the query.
+    pub fn get(&self, agent: Entity) -> Option<AgentActionsRef<'_>> {
+        self.query
+            .get(agent)
+            .ok()
+            .map(|(agent, current, queue)| AgentActionsRef::new(agent, current, queue))
+    }
+
+    /// Returns an iterator over read-only views of the actions for all agents.
+    pub fn iter(&self) -> impl Iterator<Item = AgentActionsRef<'_>> {
+        self.query
+            .iter()
+            .map(|(agent, current, queue)| AgentActionsRef::new(agent, current, queue))
+    }
+}
+
+/// A read-only view of the actions for an `agent`.
+#[derive(Debug, Clone, Copy)]
+pub struct AgentActionsRef<'a> {
+    agent: Entity,
+    current: &'a CurrentAction,
+    queue: &'a ActionQueue,
+}
+
+impl<'a> AgentActionsRef<'a> {
+    /// Returns a read-only view of the actions for `agent`.
+    pub const fn new(agent: Entity, current: &'a CurrentAction, queue: &'a ActionQueue) -> Self {
+        Self {
+            agent,
+            current,
+            queue,
+        }
+    }
+
+    /// Returns the `agent`.
+    pub const fn agent(&self) -> Entity {
+        self.agent
+    }
+
+    /// Returns the number of actions in the queue, not including the current action.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue is empty, not including the current action.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns `true` if there is no current action.
+    pub fn is_idle(&self) -> bool {
+        self.current.is_none()
+    }
+
+    /// Returns the current action.
+    pub fn current(&self) -> Option<&'a dyn Action> {
+        self.current.as_deref()
+    }
+
+    /// Returns the [`type name`](Action::type_name) of the current action.
+    pub fn current_type_name(&self) -> Option<&'static str> {
+        self.current().map(Action::type_name)
+    }
+
+    /// Returns an iterator over the actions in the queue, from front to back.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a dyn Action> + ExactSizeIterator {
+        self.queue.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns an iterator over the [`type names`](Action::type_name) of the actions in the queue,
+    /// from front to back.
+    pub fn type_names(&self) -> impl DoubleEndedIterator<Item = &'static str> + ExactSizeIterator {
+        self.iter().map(Action::type_name)
+    }
+
+    /// Returns the first action of type `T`, starting with the current action
+    /// followed by the queue from front to back.
+    pub fn find<T: Action>(&self) -> Option<&'a T> {
+        self.current()
+            .into_iter()
+            .chain(self.iter())
+            .find_map(|action| action.downcast_ref::<T>())
+    }
+
+    /// Returns `true` if the current action or any action in the queue is of type `T`.
+    pub fn contains<T: Action>(&self) -> bool {
+        self.find::<T>().is_some()
+    }
+
+    /// Returns the [`status`](ActionIdStatus) of the action with specified `id`.
+    pub fn status(&self, id: ActionId) -> ActionIdStatus {
+        if self.current.id() == Some(id) {
+            return ActionIdStatus::Current;
+        }
+
+        self.queue
+            .position(id)
+            .map_or(ActionIdStatus::Unknown, ActionIdStatus::Queued)
+    }
+}
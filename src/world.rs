@@ -52,6 +52,27 @@ impl ManageActions for AgentActions<'_> {
         self
     }
 
+    fn add_with_id(&mut self, action: impl IntoBoxedAction) -> ActionId {
+        let id = ActionId::new();
+        SequentialActionsPlugin::add_action_with_id(
+            self.agent,
+            self.config,
+            id,
+            action,
+            self.world,
+        );
+        id
+    }
+
+    fn interrupt(&mut self, actions: impl IntoBoxedActions) -> &mut Self {
+        SequentialActionsPlugin::interrupt_actions(
+            self.agent,
+            actions.into_boxed_actions(),
+            self.world,
+        );
+        self
+    }
+
     fn execute(&mut self) -> &mut Self {
         SequentialActionsPlugin::execute_actions(self.agent, self.world);
         self
@@ -63,21 +84,51 @@ impl ManageActions for AgentActions<'_> {
         self
     }
 
+    fn finish(&mut self) -> &mut Self {
+        SequentialActionsPlugin::finish_action(self.agent, self.world);
+        self
+    }
+
     fn cancel(&mut self) -> &mut Self {
         SequentialActionsPlugin::stop_current_action(self.agent, StopReason::Canceled, self.world);
         self
     }
 
+    fn cancel_if_current(&mut self, id: ActionId) -> &mut Self {
+        SequentialActionsPlugin::cancel_action_if_current(self.agent, id, self.world);
+        self
+    }
+
     fn pause(&mut self) -> &mut Self {
         SequentialActionsPlugin::stop_current_action(self.agent, StopReason::Paused, self.world);
         self
     }
 
+    fn move_to(&mut self, from: usize, to: usize) -> &mut Self {
+        SequentialActionsPlugin::move_action(self.agent, from, to, self.world);
+        self
+    }
+
+    fn swap(&mut self, a: usize, b: usize) -> &mut Self {
+        SequentialActionsPlugin::swap_actions(self.agent, a, b, self.world);
+        self
+    }
+
     fn skip(&mut self, n: usize) -> &mut Self {
         SequentialActionsPlugin::skip_actions(self.agent, n, self.world);
         self
     }
 
+    fn remove(&mut self, id: ActionId) -> &mut Self {
+        SequentialActionsPlugin::remove_action(self.agent, id, self.world);
+        self
+    }
+
+    fn retain(&mut self, f: impl FnMut(&dyn Action) -> bool + Send + 'static) -> &mut Self {
+        SequentialActionsPlugin::retain_actions(self.agent, f, self.world);
+        self
+    }
+
     fn clear(&mut self) -> &mut Self {
         SequentialActionsPlugin::clear_actions(self.agent, self.world);
         self
@@ -1,8 +1,9 @@
-use std::{marker::PhantomData, ops::Deref, usize};
+use std::{marker::PhantomData, ops::Deref, time::Duration};
 
 use bevy_app::prelude::*;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
 
 use bevy_sequential_actions::*;
 
@@ -13,7 +14,7 @@ impl TestApp {
     fn new() -> Self {
         let mut app = App::new();
         app.init_resource::<Hooks>()
-            .add_plugins(SequentialActionsPlugin)
+            .add_plugins(SequentialActionsPlugin::default())
             .add_systems(Update, (countdown, countup));
 
         Self(app)
@@ -88,6 +89,7 @@ enum Name {
     Despawn,
     GoodAdd,
     BadAdd,
+    Fail,
 }
 
 impl Name {
@@ -247,23 +249,29 @@ fn spawn_and_remove() {
     let mut app = TestApp::new();
     let a = app.world_mut().spawn(SequentialActions).id();
 
-    assert_eq!(app.entity(a).contains::<SequentialActions>(), true);
-    assert_eq!(app.entity(a).contains::<CurrentAction>(), true);
-    assert_eq!(app.entity(a).contains::<ActionQueue>(), true);
+    assert!(app.entity(a).contains::<SequentialActions>());
+    assert!(app.entity(a).contains::<CurrentAction>());
+    assert!(app.entity(a).contains::<ActionQueue>());
+    assert!(app.entity(a).contains::<Blackboard>());
+    assert!(app.entity(a).contains::<Idle>());
 
     app.world_mut().entity_mut(a).remove::<SequentialActions>();
 
-    assert_eq!(app.entity(a).contains::<SequentialActions>(), false);
-    assert_eq!(app.entity(a).contains::<CurrentAction>(), true);
-    assert_eq!(app.entity(a).contains::<ActionQueue>(), true);
+    assert!(!app.entity(a).contains::<SequentialActions>());
+    assert!(app.entity(a).contains::<CurrentAction>());
+    assert!(app.entity(a).contains::<ActionQueue>());
+    assert!(app.entity(a).contains::<Blackboard>());
+    assert!(app.entity(a).contains::<Idle>());
 
     app.world_mut()
         .entity_mut(a)
         .remove_with_requires::<SequentialActions>();
 
-    assert_eq!(app.entity(a).contains::<SequentialActions>(), false);
-    assert_eq!(app.entity(a).contains::<CurrentAction>(), false);
-    assert_eq!(app.entity(a).contains::<ActionQueue>(), false);
+    assert!(!app.entity(a).contains::<SequentialActions>());
+    assert!(!app.entity(a).contains::<CurrentAction>());
+    assert!(!app.entity(a).contains::<ActionQueue>());
+    assert!(!app.entity(a).contains::<Blackboard>());
+    assert!(!app.entity(a).contains::<Idle>());
 }
 
 #[test]
@@ -609,21 +617,21 @@ fn order() {
         MarkerAction::<C>::default(),
     ));
 
-    assert_eq!(app.entity(a).contains::<A>(), true);
-    assert_eq!(app.entity(a).contains::<B>(), false);
-    assert_eq!(app.entity(a).contains::<C>(), false);
+    assert!(app.entity(a).contains::<A>());
+    assert!(!app.entity(a).contains::<B>());
+    assert!(!app.entity(a).contains::<C>());
 
     app.update();
 
-    assert_eq!(app.entity(a).contains::<A>(), false);
-    assert_eq!(app.entity(a).contains::<B>(), true);
-    assert_eq!(app.entity(a).contains::<C>(), false);
+    assert!(!app.entity(a).contains::<A>());
+    assert!(app.entity(a).contains::<B>());
+    assert!(!app.entity(a).contains::<C>());
 
     app.update();
 
-    assert_eq!(app.entity(a).contains::<A>(), false);
-    assert_eq!(app.entity(a).contains::<B>(), false);
-    assert_eq!(app.entity(a).contains::<C>(), true);
+    assert!(!app.entity(a).contains::<A>());
+    assert!(!app.entity(a).contains::<B>());
+    assert!(app.entity(a).contains::<C>());
 
     // Front
     app.actions(a)
@@ -638,21 +646,21 @@ fn order() {
         ))
         .execute();
 
-    assert_eq!(app.entity(a).contains::<A>(), true);
-    assert_eq!(app.entity(a).contains::<B>(), false);
-    assert_eq!(app.entity(a).contains::<C>(), false);
+    assert!(app.entity(a).contains::<A>());
+    assert!(!app.entity(a).contains::<B>());
+    assert!(!app.entity(a).contains::<C>());
 
     app.update();
 
-    assert_eq!(app.entity(a).contains::<A>(), false);
-    assert_eq!(app.entity(a).contains::<B>(), true);
-    assert_eq!(app.entity(a).contains::<C>(), false);
+    assert!(!app.entity(a).contains::<A>());
+    assert!(app.entity(a).contains::<B>());
+    assert!(!app.entity(a).contains::<C>());
 
     app.update();
 
-    assert_eq!(app.entity(a).contains::<A>(), false);
-    assert_eq!(app.entity(a).contains::<B>(), false);
-    assert_eq!(app.entity(a).contains::<C>(), true);
+    assert!(!app.entity(a).contains::<A>());
+    assert!(!app.entity(a).contains::<B>());
+    assert!(app.entity(a).contains::<C>());
 }
 
 #[test]
@@ -949,3 +957,2147 @@ fn forever_action() {
     let a = app.spawn_agent();
     app.actions(a).add(ForeverAction);
 }
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn forever_retain() {
+    struct ForeverAction;
+    impl Action for ForeverAction {
+        fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+            true
+        }
+        fn on_start(&mut self, _agent: Entity, _world: &mut World) -> bool {
+            true
+        }
+        fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
+        fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, _reason: DropReason) {
+            world
+                .actions(agent.unwrap())
+                .start(false)
+                .add(self as BoxedAction);
+        }
+    }
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+    app.actions(a)
+        .start(false)
+        .add(ForeverAction)
+        .retain(|_| false);
+}
+
+#[test]
+fn lifecycle_events() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Lifecycle {
+        Started(&'static str, Entity),
+        Stopped(&'static str, Entity, StopReason),
+        Dropped(&'static str, Entity, DropReason),
+        Emptied(Entity),
+    }
+
+    #[derive(Default, Resource, Deref, DerefMut)]
+    struct Lifecycles(Vec<Lifecycle>);
+
+    let mut app = TestApp::new();
+    app.init_resource::<Lifecycles>()
+        .add_observer(|t: On<ActionStarted>, mut l: ResMut<Lifecycles>| {
+            l.push(Lifecycle::Started(t.action, t.agent));
+        })
+        .add_observer(|t: On<ActionStopped>, mut l: ResMut<Lifecycles>| {
+            l.push(Lifecycle::Stopped(t.action, t.agent, t.reason));
+        })
+        .add_observer(|t: On<ActionDropped>, mut l: ResMut<Lifecycles>| {
+            l.push(Lifecycle::Dropped(t.action, t.agent, t.reason));
+        })
+        .add_observer(|t: On<QueueEmptied>, mut l: ResMut<Lifecycles>| {
+            l.push(Lifecycle::Emptied(t.agent));
+        });
+
+    let countdown = std::any::type_name::<CountdownAction>();
+    let countup = std::any::type_name::<CountupAction>();
+
+    let a = app.spawn_agent();
+    app.actions(a)
+        .add((CountdownAction::new(0), CountupAction::new(1)));
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<Lifecycles>().0,
+        vec![
+            Lifecycle::Started(countdown, a),
+            Lifecycle::Stopped(countdown, a, StopReason::Finished),
+            Lifecycle::Dropped(countdown, a, DropReason::Done),
+            Lifecycle::Started(countup, a),
+            Lifecycle::Stopped(countup, a, StopReason::Finished),
+            Lifecycle::Dropped(countup, a, DropReason::Done),
+            Lifecycle::Emptied(a),
+        ]
+    );
+
+    app.world_mut().resource_mut::<Lifecycles>().clear();
+    app.actions(a)
+        .start(false)
+        .add((
+            CountdownAction::new(1),
+            CountupAction::new(1),
+            CountupAction::new(1),
+        ))
+        .execute()
+        .pause()
+        .skip(1)
+        .clear();
+
+    assert_eq!(
+        app.world().resource::<Lifecycles>().0,
+        vec![
+            Lifecycle::Started(countdown, a),
+            Lifecycle::Stopped(countdown, a, StopReason::Paused),
+            Lifecycle::Dropped(countdown, a, DropReason::Skipped),
+            Lifecycle::Dropped(countup, a, DropReason::Cleared),
+            Lifecycle::Dropped(countup, a, DropReason::Cleared),
+        ]
+    );
+
+    app.world_mut().resource_mut::<Lifecycles>().clear();
+    app.actions(a).add(CountdownAction::new(1)).cancel();
+
+    assert_eq!(
+        app.world().resource::<Lifecycles>().0,
+        vec![
+            Lifecycle::Started(countdown, a),
+            Lifecycle::Stopped(countdown, a, StopReason::Canceled),
+            Lifecycle::Dropped(countdown, a, DropReason::Done),
+        ]
+    );
+}
+
+#[test]
+fn action_ids() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    let countdown = app.actions(a).add_with_id(CountdownAction::new(1));
+    let countup = app.actions(a).add_with_id(CountupAction::new(1));
+    let missing = ActionId::new();
+
+    assert_ne!(countdown, countup);
+    assert_eq!(app.current_action(a).id(), Some(countdown));
+    assert_eq!(app.action_queue(a).position(countup), Some(0));
+    assert_eq!(
+        SequentialActionsPlugin::action_status(a, countdown, app.world()),
+        ActionIdStatus::Current
+    );
+    assert_eq!(
+        SequentialActionsPlugin::action_status(a, countup, app.world()),
+        ActionIdStatus::Queued(0)
+    );
+    assert_eq!(
+        SequentialActionsPlugin::action_status(a, missing, app.world()),
+        ActionIdStatus::Unknown
+    );
+
+    app.hooks_mut().clear();
+    app.actions(a)
+        .remove(countdown)
+        .cancel_if_current(countup)
+        .remove(missing)
+        .cancel_if_current(missing);
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.action_queue(a).len(), 1);
+    assert_eq!(app.hooks().deref().clone(), vec![]);
+
+    app.actions(a).remove(countup).cancel_if_current(countdown);
+
+    assert!(app.current_action(a).is_none());
+    assert!(app.action_queue(a).is_empty());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Skipped),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Canceled),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done)
+        ]
+    );
+    assert_eq!(
+        SequentialActionsPlugin::action_status(a, countdown, app.world()),
+        ActionIdStatus::Unknown
+    );
+
+    let id = app
+        .actions(a)
+        .add(CountupAction::new(2))
+        .pause()
+        .start(false)
+        .add_with_id(CountdownAction::new(1));
+
+    assert_eq!(app.action_queue(a).position(id), Some(1));
+
+    app.reset();
+    let mut commands = app.world_mut().commands();
+    let b = commands.spawn(SequentialActions).id();
+    let id = commands.actions(b).add_with_id(CountdownAction::new(1));
+    commands.actions(b).cancel_if_current(id);
+    app.world_mut().flush();
+
+    assert!(app.current_action(b).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, b),
+            Hook::Start(Name::Countdown, b),
+            Hook::Stop(Name::Countdown, Some(b), StopReason::Canceled),
+            Hook::Remove(Name::Countdown, Some(b)),
+            Hook::Drop(Name::Countdown, Some(b), DropReason::Done)
+        ]
+    );
+
+    // Ids follow their actions
+    let a = app.reset().spawn_agent();
+    let id = app.actions(a).add_with_id(CountdownAction::new(10));
+
+    assert!(
+        app.current_action(a)
+            .as_ref()
+            .unwrap()
+            .downcast_ref::<CountdownAction>()
+            .is_some()
+    );
+
+    app.actions(a).pause();
+
+    assert_eq!(app.action_queue(a).position(id), Some(0));
+
+    app.actions(a).execute();
+
+    assert_eq!(app.current_action(a).id(), Some(id));
+
+    let first = id;
+    let second = app.actions(a).add_with_id(CountupAction::new(10));
+    let third = app.actions(a).add_with_id(CountdownAction::new(10));
+    app.actions(a).pause().swap(0, 1);
+
+    assert_eq!(app.action_queue(a).position(first), Some(1));
+    assert_eq!(app.action_queue(a).position(second), Some(0));
+    assert_eq!(app.action_queue(a).position(third), Some(2));
+
+    app.actions(a).move_to(2, 0).execute();
+
+    assert_eq!(app.current_action(a).id(), Some(third));
+    assert_eq!(app.action_queue(a).position(second), Some(0));
+    assert_eq!(app.action_queue(a).position(first), Some(1));
+
+    // Action that adds itself again
+    struct Readd(u32);
+    impl Action for Readd {
+        fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+            true
+        }
+        fn on_start(&mut self, _agent: Entity, _world: &mut World) -> bool {
+            false
+        }
+        fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
+        fn on_drop(mut self: Box<Self>, agent: Option<Entity>, world: &mut World, r: DropReason) {
+            if let Some(agent) = agent
+                && r == DropReason::Done
+                && self.0 > 0
+            {
+                self.0 -= 1;
+                world.actions(agent).start(false).add(self as BoxedAction);
+            }
+        }
+    }
+
+    let a = app.reset().spawn_agent();
+    let id = app.actions(a).add_with_id(Readd(2));
+    app.actions(a).add(CountdownAction::new(10));
+    app.update();
+
+    assert_eq!(
+        SequentialActionsPlugin::action_status(a, id, app.world()),
+        ActionIdStatus::Unknown
+    );
+
+    app.actions(a).remove(id);
+
+    assert_eq!(app.action_queue(a).len(), 1);
+}
+
+#[test]
+fn inspect() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    let actions = app.world().actions_ref(a).unwrap();
+
+    assert_eq!(actions.agent(), a);
+    assert!(actions.is_idle());
+    assert!(actions.is_empty());
+    assert_eq!(actions.current_type_name(), None);
+
+    let id = app.actions(a).add_with_id(CountdownAction::new(5));
+    app.actions(a)
+        .add((CountupAction::new(1), CountdownAction::new(3)));
+
+    let actions = app.world().actions_ref(a).unwrap();
+
+    assert!(!actions.is_idle());
+    assert_eq!(actions.len(), 2);
+    assert_eq!(
+        actions.current_type_name(),
+        Some(std::any::type_name::<CountdownAction>())
+    );
+    assert_eq!(
+        actions.type_names().collect::<Vec<_>>(),
+        vec![
+            std::any::type_name::<CountupAction>(),
+            std::any::type_name::<CountdownAction>()
+        ]
+    );
+    assert_eq!(actions.find::<CountdownAction>().unwrap().count, 5);
+    assert_eq!(
+        actions.iter().last().unwrap().type_name(),
+        actions.type_names().last().unwrap()
+    );
+    assert!(actions.contains::<CountupAction>());
+    assert_eq!(actions.status(id), ActionIdStatus::Current);
+
+    assert!(app.world().actions_ref(Entity::PLACEHOLDER).is_none());
+
+    #[derive(Default, Resource)]
+    struct Plans(Vec<(Entity, usize, Option<&'static str>)>);
+
+    let b = app.spawn_agent();
+    app.init_resource::<Plans>().add_systems(
+        Update,
+        |actions_q: ActionsQuery, mut plans: ResMut<Plans>| {
+            plans.0 = actions_q
+                .iter()
+                .map(|actions| (actions.agent(), actions.len(), actions.current_type_name()))
+                .collect();
+            assert!(actions_q.get(Entity::PLACEHOLDER).is_none());
+        },
+    );
+    app.update();
+
+    let mut plans = app.world_mut().remove_resource::<Plans>().unwrap().0;
+    plans.sort_by_key(|(_, len, _)| std::cmp::Reverse(*len));
+
+    assert_eq!(
+        plans,
+        vec![
+            (a, 2, Some(std::any::type_name::<CountdownAction>())),
+            (b, 0, None)
+        ]
+    );
+}
+
+#[test]
+fn retain() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a)
+        .add((
+            CountdownAction::new(1),
+            CountupAction::new(1),
+            CountdownAction::new(2),
+            CountupAction::new(2),
+            CountdownAction::new(3),
+        ))
+        .retain(|action| {
+            action
+                .downcast_ref::<CountdownAction>()
+                .is_none_or(|action| action.count != 2)
+        });
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.action_queue(a).len(), 3);
+
+    app.hooks_mut().clear();
+    app.actions(a).remove_all::<CountupAction>();
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.action_queue(a).len(), 1);
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Skipped),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Skipped),
+        ]
+    );
+
+    app.hooks_mut().clear();
+    app.actions(a).remove_all::<CountdownAction>();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Skipped),
+        ]
+    );
+
+    app.reset().actions(a).retain(|_| false);
+}
+
+#[test]
+fn order_index_and_type() {
+    fn queued(app: &TestApp, agent: Entity) -> Vec<i32> {
+        app.world()
+            .actions_ref(agent)
+            .unwrap()
+            .iter()
+            .map(|action| match action.downcast_ref::<CountdownAction>() {
+                Some(countdown) => countdown.count,
+                None => -action.downcast_ref::<CountupAction>().unwrap().count,
+            })
+            .collect()
+    }
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).start(false).add((
+        CountdownAction::new(1),
+        CountdownAction::new(2),
+        CountdownAction::new(3),
+    ));
+
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::Index(1))
+        .add((CountupAction::new(1), CountupAction::new(2)));
+
+    assert_eq!(queued(&app, a), vec![1, -1, -2, 2, 3]);
+
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::Index(usize::MAX))
+        .add(CountupAction::new(3));
+
+    assert_eq!(queued(&app, a), vec![1, -1, -2, 2, 3, -3]);
+
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::after_type::<CountupAction>())
+        .add((CountdownAction::new(4), CountdownAction::new(5)));
+
+    assert_eq!(queued(&app, a), vec![1, -1, 4, 5, -2, 2, 3, -3]);
+
+    app.actions(a).remove_all::<CountupAction>();
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::after_type::<CountupAction>())
+        .add(CountdownAction::new(6));
+
+    assert_eq!(queued(&app, a), vec![1, 4, 5, 2, 3, 6]);
+
+    app.actions(a)
+        .move_to(0, 3)
+        .move_to(5, 0)
+        .move_to(1, usize::MAX);
+
+    assert_eq!(queued(&app, a), vec![6, 5, 2, 1, 3, 4]);
+
+    app.actions(a)
+        .swap(0, 5)
+        .swap(1, 1)
+        .swap(2, 6)
+        .move_to(6, 0);
+
+    assert_eq!(queued(&app, a), vec![4, 5, 2, 1, 3, 6]);
+
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::Index(usize::MAX))
+        .add((CountdownAction::new(7), CountdownAction::new(8)));
+
+    assert_eq!(queued(&app, a), vec![4, 5, 2, 1, 3, 6, 7, 8]);
+
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::after_type::<CountupAction>())
+        .add((CountdownAction::new(9), CountdownAction::new(10)));
+
+    assert_eq!(queued(&app, a), vec![4, 5, 2, 1, 3, 6, 7, 8, 9, 10]);
+
+    let b = app.spawn_agent();
+    app.actions(b).start(false).order(AddOrder::Index(5)).add((
+        CountdownAction::new(1),
+        CountdownAction::new(2),
+        CountdownAction::new(3),
+    ));
+
+    assert_eq!(queued(&app, b), vec![1, 2, 3]);
+}
+
+#[test]
+fn interrupt() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a)
+        .add((CountdownAction::new(10), CountupAction::new(1)));
+    app.update();
+    app.hooks_mut().clear();
+
+    app.actions(a)
+        .interrupt((CountdownAction::new(0), CountupAction::new(2)));
+
+    assert_eq!(app.action_queue(a).len(), 2);
+    assert!(app.entity(a).contains::<Countup>());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Paused),
+            Hook::Add(Name::Countup, a),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+            Hook::Start(Name::Countup, a),
+        ]
+    );
+
+    app.update();
+    app.update();
+
+    assert_eq!(app.entity(a).get::<Countdown>().unwrap().0, 9);
+    assert_eq!(app.action_queue(a).len(), 1);
+
+    app.hooks_mut().clear();
+    app.actions(a).interrupt([]);
+
+    assert_eq!(app.hooks().deref().clone(), vec![]);
+
+    let mut commands = app.world_mut().commands();
+    commands.actions(a).interrupt(CountupAction::new(1));
+    app.world_mut().flush();
+
+    assert!(!app.entity(a).contains::<Countdown>());
+    assert_eq!(app.action_queue(a).len(), 2);
+
+    app.update();
+
+    assert_eq!(app.entity(a).get::<Countdown>().unwrap().0, 9);
+
+    app.reset().actions(a).interrupt(CountdownAction::new(1));
+}
+
+#[test]
+fn replace() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a)
+        .add((CountdownAction::new(1), CountupAction::new(1)));
+    app.hooks_mut().clear();
+
+    app.actions(a)
+        .order(AddOrder::Replace)
+        .add(CountupAction::new(1));
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Canceled),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Replaced),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Replaced),
+            Hook::Add(Name::Countup, a),
+            Hook::Start(Name::Countup, a),
+        ]
+    );
+
+    app.hooks_mut().clear();
+    app.actions(a)
+        .start(false)
+        .order(AddOrder::Replace)
+        .add((CountdownAction::new(1), CountupAction::new(1)));
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.action_queue(a).len(), 2);
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Stop(Name::Countup, Some(a), StopReason::Canceled),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Replaced),
+            Hook::Add(Name::Countdown, a),
+            Hook::Add(Name::Countup, a),
+        ]
+    );
+
+    app.reset()
+        .actions(a)
+        .order(AddOrder::Replace)
+        .add(CountdownAction::new(1));
+}
+
+struct FailAction;
+
+impl Action for FailAction {
+    fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+        false
+    }
+
+    fn poll(&self, agent: Entity, world: &World) -> ActionStatus {
+        if world.get::<Countup>(agent).unwrap().0 > 0 {
+            ActionStatus::Failed
+        } else {
+            ActionStatus::Running
+        }
+    }
+
+    fn on_add(&mut self, agent: Entity, world: &mut World) {
+        Name::Fail.on_add(agent, world);
+    }
+
+    fn on_start(&mut self, agent: Entity, world: &mut World) -> bool {
+        Name::Fail.on_start(agent, world);
+        world.entity_mut(agent).insert(Countup(0));
+        false
+    }
+
+    fn on_stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+        Name::Fail.on_stop(agent, world, reason);
+        if let Some(agent) = agent {
+            world.entity_mut(agent).remove::<Countup>();
+        }
+    }
+
+    fn on_remove(&mut self, agent: Option<Entity>, world: &mut World) {
+        Name::Fail.on_remove(agent, world);
+    }
+
+    fn on_drop(self: Box<Self>, agent: Option<Entity>, world: &mut World, reason: DropReason) {
+        Name::Fail.on_drop(agent, world, reason);
+    }
+}
+
+#[test]
+fn fail() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add((FailAction, CountdownAction::new(1)));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Fail, a),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Remove(Name::Fail, Some(a)),
+            Hook::Drop(Name::Fail, Some(a), DropReason::Failed),
+            Hook::Start(Name::Countdown, a),
+        ]
+    );
+
+    assert_eq!(ActionStatus::Running.stop_reason(), None);
+    assert_eq!(
+        ActionStatus::Succeeded.stop_reason(),
+        Some(StopReason::Finished)
+    );
+    assert_eq!(ActionStatus::Failed.stop_reason(), Some(StopReason::Failed));
+}
+
+#[test]
+fn on_failure() {
+    let mut app = TestApp::new();
+
+    // Continue
+    let a = app.spawn_agent();
+    app.world_mut().entity_mut(a).insert(OnFailure::Continue);
+    app.actions(a).add((FailAction, CountdownAction::new(1)));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+    assert!(app.entity(a).contains::<Countdown>());
+
+    // Clear
+    let a = app.reset().spawn_agent();
+    app.world_mut().entity_mut(a).insert(OnFailure::Clear);
+    app.actions(a).add((FailAction, CountdownAction::new(1)));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert!(app.action_queue(a).is_empty());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Fail, a),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Remove(Name::Fail, Some(a)),
+            Hook::Drop(Name::Fail, Some(a), DropReason::Failed),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Cleared),
+        ]
+    );
+
+    // Retry
+    let a = app.reset().spawn_agent();
+    app.world_mut().entity_mut(a).insert(OnFailure::Retry);
+    app.actions(a).add((FailAction, CountdownAction::new(1)));
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.action_queue(a).len(), 1);
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Fail, a),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Start(Name::Fail, a),
+        ]
+    );
+
+    app.world_mut().entity_mut(a).remove::<OnFailure>();
+    app.update();
+
+    assert!(app.entity(a).contains::<Countdown>());
+
+    // Retry composites
+    for action in [
+        ParallelActions::new(FailAction).into_boxed_action(),
+        SelectActions::race(FailAction).into_boxed_action(),
+    ] {
+        let a = app.reset().spawn_agent();
+        app.world_mut().entity_mut(a).insert(OnFailure::Retry);
+        app.actions(a).add(action);
+        app.update();
+        app.update();
+
+        assert!(app.current_action(a).is_some());
+        assert_eq!(
+            app.hooks().deref().clone(),
+            vec![
+                Hook::Add(Name::Fail, a),
+                Hook::Start(Name::Fail, a),
+                Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+                Hook::Start(Name::Fail, a),
+                Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+                Hook::Start(Name::Fail, a),
+            ]
+        );
+    }
+}
+
+#[test]
+fn sequence() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(ActionSequence::new(actions![
+        CountdownAction::new(1),
+        CountupAction::new(1),
+    ]));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+    assert!(app.entity(a).contains::<Countup>());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Add(Name::Countup, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Start(Name::Countup, a),
+            Hook::Stop(Name::Countup, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Fail
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(ActionSequence::new(vec![
+        FailAction.into_boxed_action(),
+        CountdownAction::new(1).into_boxed_action(),
+    ]));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Fail, a),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Remove(Name::Fail, Some(a)),
+            Hook::Drop(Name::Fail, Some(a), DropReason::Failed),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Skipped),
+        ]
+    );
+
+    // Repeat
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(ActionSequence::new(actions![CountdownAction::new(1)]).repeat(1));
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Empty
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(ActionSequence::new([]).repeat(1));
+
+    assert!(app.current_action(a).is_none());
+    assert!(app.action_queue(a).is_empty());
+}
+
+#[test]
+fn parallel() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(ParallelActions::new(actions![
+        CountdownAction::new(1),
+        CountupAction::new(2),
+    ]));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Add(Name::Countup, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Start(Name::Countup, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Stop(Name::Countup, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Pause and resume
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(ParallelActions::new(actions![
+        CountdownAction::new(1),
+        CountupAction::new(2),
+    ]));
+    app.update();
+    app.actions(a).pause();
+    app.hooks_mut().clear();
+    app.actions(a).execute();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Start(Name::Countup, a),
+            Hook::Stop(Name::Countup, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Fail
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(ParallelActions::new((FailAction, CountdownAction::new(10))));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Fail, a),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Canceled),
+            Hook::Remove(Name::Fail, Some(a)),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Fail, Some(a), DropReason::Failed),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+    );
+}
+
+#[test]
+fn repeat() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a)
+        .add(RepeatAction::new(CountdownAction::new(1), 1));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+    );
+
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(RepeatAction::new(CountdownAction::new(1), u32::MAX))
+        .cancel();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Canceled),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+    );
+}
+
+#[test]
+fn nested_composites() {
+    fn starts(app: &TestApp) -> Vec<Name> {
+        app.hooks()
+            .iter()
+            .filter_map(|hook| match hook {
+                Hook::Start(name, _) => Some(*name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let mut app = TestApp::new();
+
+    // Repeat inside sequence
+    let a = app.spawn_agent();
+    app.actions(a).add(actions![
+        ActionSequence::new(actions![
+            RepeatAction::new(CountupAction::new(1), 2),
+            CountdownAction::new(1),
+        ]),
+        CountupAction::new(1),
+    ]);
+    for _ in 0..5 {
+        app.update();
+    }
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        starts(&app),
+        [
+            Name::Countup,
+            Name::Countup,
+            Name::Countup,
+            Name::Countdown,
+            Name::Countup,
+        ]
+    );
+
+    // Sequence inside repeat
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(actions![
+        RepeatAction::new(
+            ActionSequence::new(actions![CountdownAction::new(1), CountupAction::new(1)]),
+            1,
+        ),
+        CountdownAction::new(1),
+    ]);
+    for _ in 0..5 {
+        app.update();
+    }
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        starts(&app),
+        [
+            Name::Countdown,
+            Name::Countup,
+            Name::Countdown,
+            Name::Countup,
+            Name::Countdown,
+        ]
+    );
+
+    // Repeat and sequence inside parallel
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(actions![
+        ParallelActions::new(actions![
+            RepeatAction::new(CountdownAction::new(1), 1),
+            ActionSequence::new(actions![CountupAction::new(1), CountupAction::new(1)]),
+        ]),
+        FailAction,
+    ]);
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.entity(a).contains::<Countdown>());
+    assert!(app.entity(a).contains::<Countup>());
+    app.update();
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        starts(&app),
+        [
+            Name::Countdown,
+            Name::Countup,
+            Name::Countdown,
+            Name::Countup,
+            Name::Fail,
+        ]
+    );
+}
+
+#[test]
+fn select() {
+    #[derive(Component)]
+    struct Winners(Vec<usize>);
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(SelectActions::race((
+        CountdownAction::new(1),
+        CountupAction::new(10),
+    )));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Add(Name::Countup, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Start(Name::Countup, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Stop(Name::Countup, Some(a), StopReason::Canceled),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Winners
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(SelectActions::new(
+        2,
+        (CountupAction::new(2), CountdownAction::new(1)),
+    ));
+    app.update();
+
+    let select = app.world().actions_ref(a).unwrap();
+    let select = select.find::<SelectActions>().unwrap();
+    assert_eq!(select.winners().collect::<Vec<_>>(), vec![1]);
+    assert!(select.winner_as::<CountdownAction>().is_some());
+    assert!(select.winner_as::<CountupAction>().is_none());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+
+    // Winners as output
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(
+        SelectActions::race((CountupAction::new(10), CountdownAction::new(1))).then_action(
+            |winners| {
+                ActionBuilder::new()
+                    .on_start(move |agent, world: &mut World| {
+                        world.entity_mut(agent).insert(Winners(winners.clone()));
+                    })
+                    .build()
+            },
+        ),
+    );
+    app.update();
+
+    assert_eq!(app.entity(a).get::<Winners>().unwrap().0, [1]);
+
+    // Fail
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(SelectActions::race(FailAction))
+        .add(CountdownAction::new(1));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.entity(a).contains::<Countdown>());
+    assert!(
+        app.hooks()
+            .contains(&Hook::Drop(Name::Fail, Some(a), DropReason::Failed))
+    );
+}
+
+#[test]
+fn timeout() {
+    let mut app = TestApp::new();
+    app.init_resource::<Time>();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(Timeout::new(
+        CountdownAction::new(10),
+        Duration::from_secs(2),
+    ));
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.actions(a).pause();
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(5));
+    app.actions(a).execute();
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Paused),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::TimedOut),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::TimedOut),
+        ]
+    );
+
+    // Finished in time
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(Timeout::new(
+        CountdownAction::new(1),
+        Duration::from_secs(1),
+    ));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()[2],
+        Hook::Stop(Name::Countdown, Some(a), StopReason::Finished)
+    );
+
+    // OnFailure
+    let a = app.reset().spawn_agent();
+    app.world_mut().entity_mut(a).insert(OnFailure::Clear);
+    app.actions(a).add((
+        Timeout::new(CountdownAction::new(10), Duration::ZERO),
+        CountupAction::new(1),
+    ));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert!(app.action_queue(a).is_empty());
+}
+
+#[test]
+fn retry() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(Retry::new(|| FailAction, 2));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Fail, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Remove(Name::Fail, Some(a)),
+            Hook::Drop(Name::Fail, Some(a), DropReason::Failed),
+            Hook::Add(Name::Fail, a),
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Remove(Name::Fail, Some(a)),
+            Hook::Drop(Name::Fail, Some(a), DropReason::Failed),
+        ]
+    );
+
+    // Succeed
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(Retry::new(|| CountdownAction::new(1), 5));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()[2],
+        Hook::Stop(Name::Countdown, Some(a), StopReason::Finished)
+    );
+    assert_eq!(app.hooks().len(), 5);
+
+    // Backoff
+    app.init_resource::<Time>();
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(Retry::new(|| FailAction, 2).with_backoff(Duration::from_secs(1)));
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.hooks().len(), 5);
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.hooks().len(), 7);
+    assert_eq!(app.hooks()[6], Hook::Start(Name::Fail, a));
+
+    // Clear
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(Retry::new(|| FailAction, 2).with_backoff(Duration::from_secs(1)));
+    app.update();
+    app.actions(a).clear();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.hooks().len(), 5);
+
+    // Nested
+    let a = app.reset().spawn_agent();
+    let mut failed = false;
+    app.actions(a).add(actions![
+        ActionSequence::new(actions![
+            Retry::new(
+                move || {
+                    if std::mem::replace(&mut failed, true) {
+                        CountdownAction::new(1).into_boxed_action()
+                    } else {
+                        FailAction.into_boxed_action()
+                    }
+                },
+                2,
+            ),
+            CountdownAction::new(1),
+        ]),
+        CountdownAction::new(1),
+    ]);
+    for _ in 0..4 {
+        app.update();
+    }
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()
+            .iter()
+            .filter(|hook| matches!(hook, Hook::Start(..) | Hook::Stop(..)))
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![
+            Hook::Start(Name::Fail, a),
+            Hook::Stop(Name::Fail, Some(a), StopReason::Failed),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+        ]
+    );
+
+    // Repeated
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(RepeatAction::new(
+        Retry::new(|| CountdownAction::new(1), 3),
+        2,
+    ));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()
+            .iter()
+            .filter(|hook| matches!(hook, Hook::Add(..)))
+            .count(),
+        3
+    );
+    assert_eq!(
+        app.hooks()
+            .iter()
+            .filter(|hook| matches!(hook, Hook::Drop(..)))
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn if_else() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(IfElse::new(
+        |agent, world: &World| world.entity(agent).contains::<Countdown>(),
+        CountdownAction::new(1),
+        CountupAction::new(1),
+    ));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Add(Name::Countup, a),
+            Hook::Start(Name::Countup, a),
+            Hook::Stop(Name::Countup, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Remove(Name::Countup, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Skipped),
+            Hook::Drop(Name::Countup, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Repeated
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(RepeatAction::new(
+        IfElse::new(
+            |_agent, world: &World| {
+                !world
+                    .resource::<Hooks>()
+                    .iter()
+                    .any(|hook| matches!(hook, Hook::Start(..)))
+            },
+            CountdownAction::new(1),
+            CountupAction::new(1),
+        ),
+        1,
+    ));
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()
+            .iter()
+            .filter_map(|hook| match hook {
+                Hook::Start(name, _) => Some(*name),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        [Name::Countdown, Name::Countup]
+    );
+}
+
+#[test]
+fn looping() {
+    fn starts(world: &World) -> usize {
+        world
+            .resource::<Hooks>()
+            .iter()
+            .filter(|hook| matches!(hook, Hook::Start(..)))
+            .count()
+    }
+
+    let expected = |a| {
+        [
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+        .repeat(2)
+    };
+
+    // While
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add(While::new(
+        |_agent, world: &World| starts(world) < 2,
+        || CountdownAction::new(1),
+    ));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.hooks().deref().clone(), expected(a));
+
+    app.reset()
+        .actions(a)
+        .add(While::new(|_, _: &World| false, || CountdownAction::new(1)));
+
+    assert!(app.hooks().is_empty());
+
+    // RepeatUntil
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(RepeatUntil::new(
+        || CountdownAction::new(1),
+        |_agent, world: &World| starts(world) >= 2,
+    ));
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.hooks().deref().clone(), expected(a));
+
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(RepeatUntil::new(
+        || CountdownAction::new(1),
+        |_, _: &World| true,
+    ));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.hooks().len(), 5);
+
+    // Nested
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(ActionSequence::new(actions![
+        While::new(
+            |_agent, world: &World| starts(world) < 2,
+            || CountdownAction::new(1),
+        ),
+        CountupAction::new(1),
+    ]));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()
+            .iter()
+            .filter_map(|hook| match hook {
+                Hook::Start(name, _) => Some(*name),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        [Name::Countdown, Name::Countdown, Name::Countup]
+    );
+
+    // Repeated
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(RepeatAction::new(
+        RepeatUntil::new(|| CountdownAction::new(1), |_, _: &World| true),
+        1,
+    ));
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(starts(app.world()), 2);
+}
+
+#[test]
+fn wait() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a).add((
+        CountdownAction::new(2),
+        WaitUntil::new(|agent, world: &World| world.get::<Countup>(agent).unwrap().0 >= 2)
+            .on_start(|agent, world: &mut World| {
+                world.entity_mut(agent).insert(Countup(0));
+            })
+            .on_stop(|agent, world: &mut World, reason| {
+                Name::Countup.on_stop(agent, world, reason);
+            }),
+    ));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(!app.entity(a).contains::<Countup>());
+
+    app.update();
+
+    assert!(app.entity(a).contains::<Countup>());
+
+    app.update();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().last(),
+        Some(&Hook::Stop(Name::Countup, Some(a), StopReason::Finished))
+    );
+
+    // WaitWhile
+    let a = app.reset().spawn_agent();
+    app.world_mut().entity_mut(a).insert(Countdown(2));
+    app.actions(a).add(WaitWhile::new(|agent, world: &World| {
+        world.get::<Countdown>(agent).unwrap().0 > 0
+    }));
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+}
+
+#[test]
+fn builder() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    let action = ActionBuilder::new()
+        .on_add(|agent, world: &mut World| Name::Countdown.on_add(agent, world))
+        .on_start(|agent, world: &mut World| {
+            Name::Countdown.on_start(agent, world);
+            world.entity_mut(agent).insert(Countdown(1));
+        })
+        .is_finished(|agent, world: &World| world.get::<Countdown>(agent).unwrap().0 <= 0)
+        .on_stop(|agent, world: &mut World, reason| Name::Countdown.on_stop(agent, world, reason))
+        .on_remove(|agent, world: &mut World| Name::Countdown.on_remove(agent, world))
+        .on_drop(|agent, world: &mut World, reason| Name::Countdown.on_drop(agent, world, reason))
+        .named("OpenChest")
+        .build();
+
+    assert_eq!(action.type_name(), "OpenChest");
+    assert_eq!(format!("{action:?}"), "OpenChest");
+
+    app.actions(a).add(action);
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+    );
+}
+
+#[test]
+fn derive() {
+    #[derive(Action)]
+    struct InstantAction;
+
+    #[derive(Action)]
+    #[action(
+        finished = "DerivedAction::finished",
+        start = "DerivedAction::start",
+        stop = "DerivedAction::stop",
+        name = "Derived"
+    )]
+    struct DerivedAction<T: Send + Sync + 'static>(PhantomData<T>);
+
+    impl<T: Send + Sync + 'static> DerivedAction<T> {
+        fn finished(&self, agent: Entity, world: &World) -> bool {
+            world.get::<Countdown>(agent).unwrap().0 <= 0
+        }
+
+        fn start(&mut self, agent: Entity, world: &mut World) {
+            Name::Countdown.on_start(agent, world);
+            world.entity_mut(agent).insert(Countdown(1));
+        }
+
+        fn stop(&mut self, agent: Option<Entity>, world: &mut World, reason: StopReason) {
+            Name::Countdown.on_stop(agent, world, reason);
+        }
+    }
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    app.actions(a)
+        .add((InstantAction, DerivedAction::<u32>(PhantomData)));
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(
+        app.current_action(a).as_ref().unwrap().type_name(),
+        "Derived"
+    );
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+        ]
+    );
+}
+
+#[test]
+fn blackboard() {
+    const COUNT: BlackboardKey<i32> = BlackboardKey::new("count");
+    const OTHER: BlackboardKey<u32> = BlackboardKey::new("count");
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    // Write output in on_stop, and read input in on_start
+    app.actions(a).add([
+        ActionBuilder::new()
+            .on_start(|agent, world: &mut World| {
+                world.entity_mut(agent).insert(Countdown(1));
+            })
+            .is_finished(|agent, world: &World| world.get::<Countdown>(agent).unwrap().0 <= 0)
+            .on_stop(|agent, world: &mut World, _reason| {
+                let agent = agent.unwrap();
+                let count = world.get::<Countdown>(agent).unwrap().0;
+                COUNT.set(agent, world, count + 10);
+            })
+            .build(),
+        ActionBuilder::new()
+            .on_start(|agent, world: &mut World| {
+                let count = *COUNT.get(agent, world).unwrap();
+                world.entity_mut(agent).insert(Countup(count));
+            })
+            .build(),
+    ]);
+    app.update();
+
+    assert_eq!(app.entity(a).get::<Countup>().unwrap().0, 10);
+    assert_eq!(COUNT.get(a, app.world()), Some(&10));
+    assert_eq!(OTHER.get(a, app.world()), None);
+
+    let mut blackboard = app.world_mut().get_mut::<Blackboard>(a).unwrap();
+    assert_eq!(blackboard.insert(OTHER, 1), None);
+    assert_eq!(blackboard.insert(OTHER, 2), Some(1));
+    assert_eq!(blackboard.len(), 2);
+    assert_eq!(blackboard.remove(COUNT), Some(10));
+    assert!(!blackboard.contains(COUNT));
+    assert!(blackboard.contains(OTHER));
+
+    // Clear
+    app.actions(a).clear();
+
+    assert_eq!(OTHER.get(a, app.world()), Some(&2));
+
+    app.world_mut()
+        .entity_mut(a)
+        .insert(Blackboard::new().reset_on_clear(true));
+    OTHER.set(a, app.world_mut(), 3);
+    app.actions(a).clear();
+
+    assert!(app.entity(a).get::<Blackboard>().unwrap().is_empty());
+
+    // Bundle
+    for bundle in [ActionsBundle::new(), ActionsBundle::with_capacity(1)] {
+        let b = app.world_mut().spawn(bundle).id();
+        OTHER.set(b, app.world_mut(), 4);
+
+        assert_eq!(OTHER.get(b, app.world()), Some(&4));
+    }
+}
+
+impl ActionWithOutput for CountdownAction {
+    type Output = ();
+
+    fn take_output(&mut self, _agent: Entity, _world: &mut World) -> Option<()> {
+        Some(())
+    }
+}
+
+#[test]
+fn then() {
+    struct Produce(i32);
+
+    impl Action for Produce {
+        fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+            true
+        }
+
+        fn on_start(&mut self, _agent: Entity, _world: &mut World) -> bool {
+            true
+        }
+
+        fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
+    }
+
+    impl ActionWithOutput for Produce {
+        type Output = i32;
+
+        fn take_output(&mut self, _agent: Entity, _world: &mut World) -> Option<i32> {
+            Some(self.0)
+        }
+    }
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    let then = Produce(1)
+        .then(CountdownAction::new)
+        .then(|()| Produce(5))
+        .then(|n| Produce(n * 10));
+
+    assert_eq!(then.remaining(), 3);
+
+    app.actions(a).add(then);
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a)
+        ]
+    );
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks().deref().clone(),
+        vec![
+            Hook::Add(Name::Countdown, a),
+            Hook::Start(Name::Countdown, a),
+            Hook::Stop(Name::Countdown, Some(a), StopReason::Finished),
+            Hook::Remove(Name::Countdown, Some(a)),
+            Hook::Drop(Name::Countdown, Some(a), DropReason::Done),
+        ]
+    );
+
+    // Output
+    let mut then = Produce(2).then(|n| Produce(n * 10));
+
+    assert_eq!(then.take_output(a, app.world_mut()), None);
+
+    app.actions(a).add(Produce(3).then_action(|n| {
+        ActionBuilder::new()
+            .on_start(move |agent, world: &mut World| {
+                world.entity_mut(agent).insert(Countup(n));
+            })
+            .build()
+    }));
+
+    assert_eq!(app.entity(a).get::<Countup>().unwrap().0, 3);
+
+    // Nested
+    let a = app.reset().spawn_agent();
+    app.actions(a).add(ActionSequence::new(actions![
+        CountdownAction::new(1).then_action(|()| CountupAction::new(1)),
+        CountdownAction::new(1),
+    ]));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.hooks()
+            .iter()
+            .filter_map(|hook| match hook {
+                Hook::Start(name, _) => Some(*name),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        [Name::Countdown, Name::Countup, Name::Countdown]
+    );
+}
+
+#[test]
+fn run_system() {
+    fn insert_countup(In(agent): In<Entity>, mut commands: Commands) {
+        commands.entity(agent).insert(Countup(0));
+    }
+
+    fn insert_countdown(In((agent, count)): In<(Entity, i32)>, mut commands: Commands) {
+        commands.entity(agent).insert(Countdown(count));
+    }
+
+    fn countup_done(In(agent): In<Entity>, countup_q: Query<&Countup>) -> bool {
+        countup_q.get(agent).is_ok_and(|countup| countup.0 >= 2)
+    }
+
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+
+    let insert_countup = app.world_mut().register_system(insert_countup);
+    let insert_countdown = app.world_mut().register_system(insert_countdown);
+    let countup_done = app.world_mut().register_system(countup_done);
+
+    app.actions(a).add((
+        RunSystem::new(insert_countup),
+        RunSystem::with_input(insert_countdown, 5),
+        RunSystem::until(countup_done),
+    ));
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.entity(a).get::<Countup>().unwrap().0, 0);
+    assert_eq!(app.entity(a).get::<Countdown>().unwrap().0, 5);
+
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+
+    // Fail
+    let a = app.reset().spawn_agent();
+    app.world_mut().unregister_system(insert_countup).unwrap();
+    app.actions(a).add(RunSystem::new(insert_countup));
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert!(!app.entity(a).contains::<Countup>());
+
+    // Nested
+    #[derive(Default, Resource, Deref, DerefMut)]
+    struct Stopped(usize);
+
+    app.init_resource::<Stopped>()
+        .add_observer(|_: On<ActionStopped>, mut s: ResMut<Stopped>| **s += 1);
+    let a = app.reset().spawn_agent();
+    app.world_mut().entity_mut(a).insert(Countup(0));
+    app.actions(a).add(ActionSequence::new(actions![
+        RunSystem::until(countup_done),
+        RunSystem::with_input(insert_countdown, 7),
+    ]));
+    app.update();
+
+    assert!(!app.entity(a).contains::<Countdown>());
+
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.entity(a).get::<Countdown>().unwrap().0, 7);
+    assert_eq!(**app.world().resource::<Stopped>(), 1);
+
+    // Repeated
+    #[derive(Default, Resource, Deref, DerefMut)]
+    struct Runs(usize);
+
+    fn add_runs(In((_agent, n)): In<(Entity, usize)>, mut runs: ResMut<Runs>) {
+        **runs += n;
+    }
+
+    let add_runs = app.world_mut().register_system(add_runs);
+    app.init_resource::<Runs>();
+    let a = app.reset().spawn_agent();
+    app.actions(a)
+        .add(RepeatAction::new(RunSystem::with_input(add_runs, 2), 2));
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(**app.world().resource::<Runs>(), 6);
+}
+
+#[test]
+fn finish_action() {
+    struct EventDriven;
+    impl Action for EventDriven {
+        fn is_finished(&self, _agent: Entity, _world: &World) -> bool {
+            true
+        }
+        fn is_polled(&self) -> bool {
+            false
+        }
+        fn on_start(&mut self, _agent: Entity, _world: &mut World) -> bool {
+            false
+        }
+        fn on_stop(&mut self, _agent: Option<Entity>, _world: &mut World, _reason: StopReason) {}
+    }
+
+    impl ActionWithOutput for EventDriven {
+        type Output = ();
+
+        fn take_output(&mut self, _agent: Entity, _world: &mut World) -> Option<()> {
+            Some(())
+        }
+    }
+
+    #[derive(Default, Resource, Deref, DerefMut)]
+    struct Stopped(Vec<StopReason>);
+
+    let mut app = TestApp::new();
+    app.init_resource::<Stopped>()
+        .add_observer(|t: On<ActionStopped>, mut s: ResMut<Stopped>| s.push(t.reason));
+    let a = app.spawn_agent();
+    app.actions(a).add(EventDriven);
+    app.actions(a).add_with_id(EventDriven);
+    app.actions(a).add(EventDriven);
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.action_queue(a).len(), 2);
+
+    app.actions(a).finish();
+
+    assert!(app.current_action(a).is_some());
+    assert_eq!(app.action_queue(a).len(), 1);
+
+    app.world_mut().trigger(FinishAction { agent: a });
+    app.update();
+
+    assert!(app.current_action(a).is_some());
+    assert!(app.action_queue(a).is_empty());
+
+    app.world_mut().commands().actions(a).finish();
+    app.update();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(
+        app.world().resource::<Stopped>().0,
+        [StopReason::Finished; 3]
+    );
+
+    // Nested
+    let a = app.spawn_agent();
+    app.world_mut().resource_mut::<Stopped>().clear();
+    app.actions(a).add(
+        EventDriven
+            .then(|()| EventDriven)
+            .then_action(|()| RepeatAction::new(EventDriven, 2)),
+    );
+    app.update();
+
+    for _ in 0..4 {
+        app.actions(a).finish();
+        app.update();
+
+        assert!(app.current_action(a).is_some());
+        assert!(app.world().resource::<Stopped>().is_empty());
+    }
+
+    app.actions(a).finish();
+
+    assert!(app.current_action(a).is_none());
+    assert_eq!(app.world().resource::<Stopped>().0, [StopReason::Finished]);
+}
+
+#[test]
+fn idle() {
+    let mut app = TestApp::new();
+    let a = app.spawn_agent();
+    let b = app.world_mut().spawn(ActionsBundle::new()).id();
+
+    assert!(app.entity(a).contains::<Idle>());
+    assert!(app.entity(b).contains::<Idle>());
+
+    app.actions(a)
+        .add((CountdownAction::new(1), CountdownAction::new(1)));
+
+    assert!(!app.entity(a).contains::<Idle>());
+    assert!(app.entity(b).contains::<Idle>());
+
+    let idle = app
+        .world_mut()
+        .query_filtered::<Entity, With<Idle>>()
+        .iter(app.world())
+        .collect::<Vec<_>>();
+    assert_eq!(idle, [b]);
+
+    app.update();
+
+    assert!(!app.entity(a).contains::<Idle>());
+
+    app.update();
+
+    assert!(app.entity(a).contains::<Idle>());
+
+    app.actions(a).add(CountdownAction::new(1)).pause();
+
+    assert!(app.entity(a).contains::<Idle>());
+
+    app.actions(a).execute();
+
+    assert!(!app.entity(a).contains::<Idle>());
+
+    app.actions(a).clear();
+
+    assert!(app.entity(a).contains::<Idle>());
+}
+
+#[test]
+fn check_actions_variants() {
+    check_actions_system(SequentialActionsPlugin::check_actions_parallel::<()>);
+    check_actions_system(SequentialActionsPlugin::check_actions_exclusive::<()>);
+}
+
+fn check_actions_system<M>(system: impl IntoScheduleConfigs<bevy_ecs::system::ScheduleSystem, M>) {
+    let mut app = App::new();
+    app.init_resource::<Hooks>()
+        .add_systems(Update, countdown)
+        .add_systems(Last, system);
+
+    let agents = (0..8)
+        .map(|i| {
+            let agent = app.world_mut().spawn(SequentialActions).id();
+            app.world_mut()
+                .actions(agent)
+                .add((CountdownAction::new(i % 2 + 1), CountdownAction::new(1)));
+            agent
+        })
+        .collect::<Vec<_>>();
+
+    app.update();
+
+    for (i, &agent) in agents.iter().enumerate() {
+        let queue_len = app.world().get::<ActionQueue>(agent).unwrap().len();
+        assert_eq!(queue_len, i % 2);
+        assert!(!app.world().entity(agent).contains::<Idle>());
+    }
+
+    app.update();
+    app.update();
+
+    for &agent in &agents {
+        assert!(app.world().get::<CurrentAction>(agent).unwrap().is_none());
+        assert!(app.world().entity(agent).contains::<Idle>());
+    }
+}
+
+#[test]
+fn configured_plugin() {
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, bevy_ecs::schedule::ScheduleLabel)]
+    struct CustomSchedule;
+
+    let mut app = App::new();
+    app.init_resource::<Hooks>()
+        .add_plugins((
+            SequentialActionsPlugin::new()
+                .with_filter::<Without<Marker>>()
+                .with_cleanup(CleanupMode::None),
+            SequentialActionsPlugin::new()
+                .in_schedule(CustomSchedule)
+                .with_filter::<With<Marker>>()
+                .with_cleanup(CleanupMode::Observers),
+        ))
+        .add_systems(Update, countdown);
+
+    let a = app.world_mut().spawn(SequentialActions).id();
+    let b = app.world_mut().spawn((SequentialActions, Marker)).id();
+    for agent in [a, b] {
+        app.world_mut().actions(agent).add((
+            CountdownAction::new(1),
+            CountdownAction::new(1),
+            CountdownAction::new(1),
+        ));
+    }
+
+    app.update();
+
+    assert_eq!(app.world().get::<ActionQueue>(a).unwrap().len(), 1);
+    assert_eq!(app.world().get::<ActionQueue>(b).unwrap().len(), 2);
+
+    app.world_mut().run_schedule(CustomSchedule);
+
+    assert_eq!(app.world().get::<ActionQueue>(b).unwrap().len(), 1);
+
+    app.world_mut().trigger(FinishAction { agent: a });
+    app.world_mut().flush();
+
+    assert!(app.world().get::<CurrentAction>(a).unwrap().is_some());
+    assert!(app.world().get::<ActionQueue>(a).unwrap().is_empty());
+
+    let dropped = |world: &World| {
+        world
+            .resource::<Hooks>()
+            .iter()
+            .filter(|hook| matches!(hook, Hook::Drop(_, None, _)))
+            .count()
+    };
+
+    app.world_mut().despawn(b);
+    app.world_mut().flush();
+
+    assert_eq!(dropped(app.world()), 2);
+
+    app.world_mut().despawn(a);
+    app.world_mut().flush();
+
+    assert_eq!(dropped(app.world()), 2);
+}